@@ -0,0 +1,117 @@
+use error::*;
+use oauth::Scope;
+
+use std::fmt;
+
+/// Credentials used to authorize a request to Unsplash.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub enum Auth {
+    /// The application's access key, which only grants public access.
+    PublicAccessKey(String),
+    /// A user's bearer token, which grants access on behalf of that user.
+    UserBearer(Bearer),
+}
+
+/// A bearer token which grants access to Unsplash on behalf of a user.
+///
+/// Endpoints which act on behalf of a user take this type as an argument, so
+/// they cannot be accessed using an application's access key.
+///
+/// If the scopes granted to the token are known, endpoints check the token has
+/// the scope they need before sending a request.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Bearer {
+    token: String,
+    scopes: Option<Vec<Scope>>,
+}
+
+impl Auth {
+    /// The value of the Authorization header used to send these credentials.
+    pub fn header(&self) -> String {
+        match *self {
+            Auth::PublicAccessKey(ref key) => format!("Client-ID {}", key),
+            Auth::UserBearer(ref bearer) => format!("Bearer {}", bearer.token),
+        }
+    }
+}
+
+impl Bearer {
+    /// Create a bearer from a user's access token, whose scopes are unknown.
+    pub fn new(token: String) -> Self { Bearer { token, scopes: None } }
+
+    /// Create a bearer from a user's access token, which grants the given
+    /// scopes.
+    pub fn with_scopes(token: String, scopes: Vec<Scope>) -> Self {
+        Bearer { token, scopes: Some(scopes) }
+    }
+
+    /// The scopes granted to the token, if known.
+    pub fn scopes(&self) -> Option<&[Scope]> { self.scopes.as_ref().map(Vec::as_slice) }
+
+    /// Does the token grant the given scope.
+    ///
+    /// Always true if the scopes granted to the token are unknown, leaving
+    /// Unsplash to decide.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes.as_ref().map_or(true, |scopes| scopes.contains(&scope))
+    }
+
+    /// Credentials for endpoints which act on behalf of the user, and need the
+    /// given scope.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the token is known not to grant the scope.
+    pub(crate) fn auth(&self, scope: Scope) -> Result<Auth, Error> {
+        if self.has_scope(scope) {
+            Ok(Auth::from(self))
+        } else {
+            Err(Error::from(ErrorKind::MissingScope(scope)))
+        }
+    }
+}
+
+impl From<Bearer> for Auth {
+    fn from(bearer: Bearer) -> Self { Auth::UserBearer(bearer) }
+}
+
+impl<'a> From<&'a Bearer> for Auth {
+    fn from(bearer: &'a Bearer) -> Self { Auth::UserBearer(bearer.clone()) }
+}
+
+// Debug is written by hand so credentials are never printed.
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Auth::PublicAccessKey(_) => f.debug_tuple("PublicAccessKey").field(&"..").finish(),
+            Auth::UserBearer(ref bearer) => f.debug_tuple("UserBearer").field(bearer).finish(),
+        }
+    }
+}
+
+impl fmt::Debug for Bearer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Bearer").field("token", &"..").field("scopes", &self.scopes).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header() {
+        assert_eq!(Auth::PublicAccessKey("key".to_owned()).header(), "Client-ID key");
+        assert_eq!(Auth::from(Bearer::new("tok".to_owned())).header(), "Bearer tok");
+    }
+
+    #[test]
+    fn auth() {
+        let bearer = Bearer::with_scopes("tok".to_owned(), vec![Scope::Public]);
+        assert!(bearer.auth(Scope::Public).is_ok());
+        assert_eq!(
+            bearer.auth(Scope::WriteUser).unwrap_err().kind(),
+            ErrorKind::MissingScope(Scope::WriteUser)
+        );
+        assert!(Bearer::new("tok".to_owned()).auth(Scope::WriteUser).is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+use auth::{Auth, Bearer};
+use hyper::{client::connect::Connect, Client};
+
+use endpoint::{collections::Collections, me::Me, photos::Photos, search::Search, stats::Stats,
+               topics::Topics, users::Users};
+use oauth::OAuth;
+
+use std::fmt;
+
+/// Client for accessing the Unsplash API.
+///
+/// Owns the Hyper client used to send requests, along with the access key and
+/// root URI used for every endpoint, so they only need to be configured once.
+///
+/// Endpoints which act on behalf of a user take the user's
+/// [Bearer](../auth/struct.Bearer.html) token as an argument instead, so the
+/// access key is never sent in its place.
+pub struct Unsplash<C> {
+    client: Client<C>,
+    access_key: String,
+    api_url: String,
+}
+
+impl<C> Unsplash<C>
+where
+    C: Connect + 'static,
+{
+    /// Create a new client which accesses Unsplash through the given Hyper
+    /// client using the application's access key.
+    pub fn new(client: Client<C>, access_key: String) -> Self {
+        Unsplash { client, access_key, api_url: ::API_URL.to_owned() }
+    }
+
+    /// Specify the root URI of the API, instead of [API_URL](../constant.API_URL.html).
+    ///
+    /// The URI must end with a '/'.
+    pub fn api_url(mut self, api_url: String) -> Self {
+        self.api_url = api_url;
+        self
+    }
+
+    /// Access the Collections endpoint.
+    pub fn collections(&self) -> Collections<'_, C> { Collections::new(self) }
+
+    /// Access the Photos endpoint.
+    pub fn photos(&self) -> Photos<'_, C> { Photos::new(self) }
+
+    /// Access the Me endpoint on behalf of the user the bearer token belongs
+    /// to.
+    pub fn me<'a>(&'a self, bearer: &'a Bearer) -> Me<'a, C> { Me::new(self, bearer) }
+
+    /// Access the Search endpoint.
+    pub fn search(&self) -> Search<'_, C> { Search::new(self) }
+
+    /// Access the Stats endpoint.
+    pub fn stats(&self) -> Stats<'_, C> { Stats::new(self) }
+
+    /// Access the Topics endpoint.
+    pub fn topics(&self) -> Topics<'_, C> { Topics::new(self) }
+
+    /// Access the Users endpoint.
+    pub fn users(&self) -> Users<'_, C> { Users::new(self) }
+
+    /// Authorize access on behalf of a user, using the application's secret
+    /// key and the URI Unsplash redirects the user to once authorized.
+    pub fn oauth(&self, secret_key: String, redirect_uri: String) -> OAuth<'_, C> {
+        OAuth::new(self, secret_key, redirect_uri)
+    }
+
+    /// The application's access key.
+    pub(crate) fn access_key(&self) -> &str { &self.access_key }
+
+    /// The Hyper client used to send requests.
+    pub(crate) fn http(&self) -> &Client<C> { &self.client }
+
+    /// Create the full URI of the given endpoint path.
+    pub(crate) fn url(&self, path: &str) -> String { format!("{}{}", self.api_url, path) }
+
+    /// Credentials for endpoints which only need public access.
+    pub(crate) fn auth(&self) -> Auth { Auth::PublicAccessKey(self.access_key.clone()) }
+}
+
+impl<C> Clone for Unsplash<C> {
+    fn clone(&self) -> Self {
+        Unsplash {
+            client: self.client.clone(),
+            access_key: self.access_key.clone(),
+            api_url: self.api_url.clone(),
+        }
+    }
+}
+
+impl<C> fmt::Debug for Unsplash<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Unsplash")
+            .field("client", &self.client)
+            .field("api_url", &self.api_url)
+            .finish()
+    }
+}
@@ -0,0 +1,64 @@
+use auth::Bearer;
+use client::Unsplash;
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
+use oauth::Scope;
+
+use super::Collection;
+use error::*;
+
+/// Path of the endpoint to create a collection.
+pub const CREATE_PATH: &str = "collections";
+
+/// Session type for handling collection creation
+#[derive(Debug, Serialize)]
+pub struct CollectionCreate<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    bearer: &'a Bearer,
+    title: String,
+    description: Option<String>,
+    private: Option<bool>,
+}
+
+impl<'a, C> CollectionCreate<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, bearer: &'a Bearer, title: String) -> Self {
+        CollectionCreate { client, bearer, title, description: None, private: None }
+    }
+
+    /// Set the collection's description.
+    pub fn description(mut self, description: String) -> Self {
+        self.description.replace(description);
+        self
+    }
+
+    /// Set whether the collection is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private.replace(private);
+        self
+    }
+
+    /// Create the collection.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_collections.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn create(self) -> impl Future<Item = Collection, Error = Error> {
+        let client = self.client;
+        match self.bearer.auth(Scope::WriteCollections) {
+            Ok(auth) => {
+                Either::A(::endpoint::post(&self, client, &auth, client.url(CREATE_PATH)))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+}
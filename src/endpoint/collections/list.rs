@@ -0,0 +1,79 @@
+use client::Unsplash;
+use endpoint::Page;
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use super::Collection;
+use error::*;
+
+/// Path of the endpoint to get a list of collections from Unsplash.
+pub const LIST_PATH: &str = "collections";
+
+/// Request builder for creating a collection List request.
+#[derive(Debug, Serialize, Clone)]
+pub struct List<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl<'a, C> List<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self {
+        List { client, page: None, per_page: None }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many collections per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Get the list of collections.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = Page<Collection>, Error = Error> {
+        let client = self.client;
+        ::endpoint::get_page(&self, client, &client.auth(), client.url(LIST_PATH))
+    }
+
+    /// Stream all the collections, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Collection, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, Vec<Collection>>(&self, self.client, LIST_PATH, page)
+    }
+}
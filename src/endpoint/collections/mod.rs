@@ -0,0 +1,306 @@
+//! Collections endpoint
+//!
+//! Access to the endpoint is through the [Collections](struct.Collections.html)
+//! struct, which is created by
+//! [Unsplash::collections](../../client/struct.Unsplash.html#method.collections).
+
+use auth::Bearer;
+use chrono::{DateTime, FixedOffset};
+use client::Unsplash;
+use endpoint::{me::{User, UserSummary},
+               photos::{Photo, PhotoSummary}};
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
+use oauth::Scope;
+
+mod create;
+mod list;
+mod photos;
+mod update;
+
+use error::*;
+
+pub use self::{create::CollectionCreate, list::List, photos::CollectionPhotos,
+               update::CollectionUpdate};
+
+/// Access type to Unsplash's Collections endpoint.
+#[derive(Debug, Clone)]
+pub struct Collections<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
+
+/// A collection of photos on Unsplsash
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Collection {
+    /// Collection ID
+    pub id: String,
+    /// Collection's title
+    pub title: String,
+    /// Collection's description.
+    pub description: Option<String>,
+    /// Date when collection was published.
+    pub published_at: DateTime<FixedOffset>,
+    /// Last date when the collection was updated.
+    pub updated_at: DateTime<FixedOffset>,
+    /// Is the collection curated.
+    pub curated: Option<bool>,
+    /// Number of photos in the collection.
+    pub total_photos: Option<usize>,
+    /// Is the collection private.
+    pub private: Option<bool>,
+    /// Key to share the collection, if it is private.
+    pub share_key: Option<String>,
+    /// Photo used as the collection's cover.
+    pub cover_photo: Option<Photo>,
+    /// User who created the collection.
+    pub user: Option<User>,
+    /// Links to the collection.
+    pub links: Option<CollectionLinks>,
+}
+
+/// A photo added to or removed from a collection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CollectionPhoto {
+    /// The photo which was added or removed.
+    pub photo: PhotoSummary,
+    /// The collection the photo was added to or removed from.
+    pub collection: Collection,
+    /// The user who added or removed the photo.
+    pub user: UserSummary,
+    /// When the photo was added or removed.
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Serialization type for adding or removing a photo.
+#[derive(Debug, Serialize)]
+struct PhotoSerialize<'a> {
+    photo_id: &'a str,
+}
+
+/// Links to a collection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CollectionLinks {
+    /// API link to the collection.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// Link to the collection.
+    pub html: String,
+    /// API link to the collection's photos.
+    pub photos: String,
+    /// API link to the collections related to the collection.
+    pub related: Option<String>,
+}
+
+impl<'a, C> Collections<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Collections { client } }
+
+    /// Get a list of collections from Unsplash
+    pub fn list(&self) -> List<'a, C> { List::new(self.client) }
+
+    /// Get the collection with the given ID from Unsplash.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(&self, id: &str) -> impl Future<Item = Collection, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(&format!("collections/{}", id)))
+    }
+
+    /// Get the photos in the collection with the given ID from Unsplash.
+    pub fn photos(&self, id: &str) -> CollectionPhotos<'a, C> {
+        CollectionPhotos::new(self.client, id.to_owned())
+    }
+
+    /// Get the collections related to the collection with the given ID from
+    /// Unsplash.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn related(&self, id: &str) -> impl Future<Item = Vec<Collection>, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("collections/{}/related", id));
+        ::endpoint::get(&(), client, &client.auth(), uri)
+    }
+
+    /// Create a collection on behalf of the user the bearer token belongs to.
+    pub fn create(&self, title: String, bearer: &'a Bearer) -> CollectionCreate<'a, C> {
+        CollectionCreate::new(self.client, bearer, title)
+    }
+
+    /// Update the collection with the given ID on behalf of the user the
+    /// bearer token belongs to.
+    pub fn update(&self, id: &str, bearer: &'a Bearer) -> CollectionUpdate<'a, C> {
+        CollectionUpdate::new(self.client, bearer, id.to_owned())
+    }
+
+    /// Delete the collection with the given ID on behalf of the user the
+    /// bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_collections.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn delete(&self, id: &str, bearer: &Bearer) -> impl Future<Item = (), Error = Error> {
+        let client = self.client;
+        match bearer.auth(Scope::WriteCollections) {
+            Ok(auth) => {
+                let uri = client.url(&format!("collections/{}", id));
+                Either::A(::endpoint::delete(&(), client, &auth, uri).map(::endpoint::ignore))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+
+    /// Add the photo with the given ID to the collection with the given ID, on
+    /// behalf of the user the bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_collections.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn add_photo(
+        &self,
+        collection_id: &str,
+        photo_id: &str,
+        bearer: &Bearer,
+    ) -> impl Future<Item = CollectionPhoto, Error = Error> {
+        let client = self.client;
+        match bearer.auth(Scope::WriteCollections) {
+            Ok(auth) => {
+                let uri = client.url(&format!("collections/{}/add", collection_id));
+                Either::A(::endpoint::post(&PhotoSerialize { photo_id }, client, &auth, uri))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+
+    /// Remove the photo with the given ID from the collection with the given
+    /// ID, on behalf of the user the bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_collections.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn remove_photo(
+        &self,
+        collection_id: &str,
+        photo_id: &str,
+        bearer: &Bearer,
+    ) -> impl Future<Item = CollectionPhoto, Error = Error> {
+        let client = self.client;
+        match bearer.auth(Scope::WriteCollections) {
+            Ok(auth) => {
+                let uri = client.url(&format!("collections/{}/remove", collection_id));
+                Either::A(::endpoint::delete(&PhotoSerialize { photo_id }, client, &auth, uri))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A photo, collection and user as returned from adding a photo to a
+    /// collection.
+    const COLLECTION_PHOTO: &str = r##"{
+        "photo": {
+            "id": "4kQA1aQK8-Y",
+            "created_at": "2016-05-29T15:42:02-04:00",
+            "width": 2448,
+            "height": 1836,
+            "color": "#A7A2A1",
+            "likes": 286,
+            "liked_by_user": false,
+            "description": "A man drinking a coffee.",
+            "user": {
+                "id": "pXhwzz1JtQU",
+                "username": "poorkane",
+                "name": "Gilbert Kane",
+                "links": {
+                    "self": "https://api.unsplash.com/users/poorkane",
+                    "html": "https://unsplash.com/poorkane",
+                    "photos": "https://api.unsplash.com/users/poorkane/photos",
+                    "likes": "https://api.unsplash.com/users/poorkane/likes"
+                }
+            },
+            "current_user_collections": [],
+            "urls": {
+                "raw": "https://images.unsplash.com/photo-1464822759023-fed622ff2c3b",
+                "full": "https://images.unsplash.com/photo-1464822759023-fed622ff2c3b?q=75&fm=jpg",
+                "regular": "https://images.unsplash.com/photo-1464822759023-fed622ff2c3b?w=1080",
+                "small": "https://images.unsplash.com/photo-1464822759023-fed622ff2c3b?w=400",
+                "thumb": "https://images.unsplash.com/photo-1464822759023-fed622ff2c3b?w=200"
+            },
+            "links": {
+                "self": "https://api.unsplash.com/photos/4kQA1aQK8-Y",
+                "html": "https://unsplash.com/photos/4kQA1aQK8-Y",
+                "download": "https://unsplash.com/photos/4kQA1aQK8-Y/download"
+            }
+        },
+        "collection": {
+            "id": "296",
+            "title": "I like a man with a beard.",
+            "published_at": "2016-01-12T18:16:09-05:00",
+            "last_collected_at": "2016-06-02T13:10:03-04:00",
+            "updated_at": "2016-07-10T11:00:01-05:00",
+            "curated": false,
+            "total_photos": 12,
+            "private": false,
+            "share_key": "74fa0c5e1a0e8de8d2cb0d4dea13f9a0",
+            "cover_photo": null,
+            "user": null,
+            "links": {
+                "self": "https://api.unsplash.com/collections/296",
+                "html": "https://unsplash.com/collections/296",
+                "photos": "https://api.unsplash.com/collections/296/photos"
+            }
+        },
+        "user": {
+            "id": "8VpB0GYJMZQ",
+            "username": "williamnot",
+            "name": "Thomas R.",
+            "links": {
+                "self": "https://api.unsplash.com/users/williamnot",
+                "html": "https://unsplash.com/williamnot",
+                "photos": "https://api.unsplash.com/users/williamnot/photos",
+                "likes": "https://api.unsplash.com/users/williamnot/likes"
+            }
+        },
+        "created_at": "2016-03-31T09:35:55-04:00"
+    }"##;
+
+    #[test]
+    fn collection_photo() {
+        let added: CollectionPhoto = ::serde_json::from_str(COLLECTION_PHOTO).unwrap();
+        assert_eq!(added.photo.id, "4kQA1aQK8-Y");
+        assert_eq!(added.collection.id, "296");
+        assert_eq!(added.collection.total_photos, Some(12));
+        assert_eq!(added.user.username, "williamnot");
+        assert!(added.created_at.is_some());
+    }
+}
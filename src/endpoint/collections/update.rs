@@ -0,0 +1,70 @@
+use auth::Bearer;
+use client::Unsplash;
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
+use oauth::Scope;
+
+use super::Collection;
+use error::*;
+
+/// Session type for handling collection updates
+#[derive(Debug, Serialize)]
+pub struct CollectionUpdate<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    bearer: &'a Bearer,
+    #[serde(skip)]
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    private: Option<bool>,
+}
+
+impl<'a, C> CollectionUpdate<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, bearer: &'a Bearer, id: String) -> Self {
+        CollectionUpdate { client, bearer, id, title: None, description: None, private: None }
+    }
+
+    /// Update the collection's title.
+    pub fn title(mut self, title: String) -> Self {
+        self.title.replace(title);
+        self
+    }
+
+    /// Update the collection's description.
+    pub fn description(mut self, description: String) -> Self {
+        self.description.replace(description);
+        self
+    }
+
+    /// Update whether the collection is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private.replace(private);
+        self
+    }
+
+    /// Update the collection.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_collections.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn update(self) -> impl Future<Item = Collection, Error = Error> {
+        let client = self.client;
+        match self.bearer.auth(Scope::WriteCollections) {
+            Ok(auth) => {
+                let uri = client.url(&format!("collections/{}", self.id));
+                Either::A(::endpoint::put(&self, client, &auth, uri))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+}
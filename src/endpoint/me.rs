@@ -1,16 +1,21 @@
-use futures::Future;
-use hyper::{client::connect::Connect, Client, Uri};
+use auth::Bearer;
+use client::Unsplash;
+use endpoint::photos::Tag;
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
 
 use error::Error;
+use oauth::Scope;
 
-lazy_static! {
-    /// URI of the endpoint to get a list of photos from Unsplash.
-    pub static ref ME_URI: Uri = format!("{}{}", ::API_URL, "me").parse().unwrap();
-}
+/// Path of the endpoint to access the current user.
+pub const ME_PATH: &str = "me";
 
 /// Me endpoint
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
-pub struct Me;
+#[derive(Debug, Clone)]
+pub struct Me<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+    bearer: &'a Bearer,
+}
 
 /// A User on Unsplash
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +52,67 @@ pub struct User {
     pub updated_at: Option<String>,
     /// Is this user followed by the user who accessed the api.
     pub followed_by_user: Option<bool>,
+    /// Badge shown on the user's profile (only when getting a single user).
+    pub badge: Option<Badge>,
+    /// Tags describing the user's photos (only when getting a single user).
+    pub tags: Option<UserTags>,
+    /// Number of users following the user (only when getting a single user).
+    pub followers_count: Option<usize>,
+    /// Number of users the user follows (only when getting a single user).
+    pub following_count: Option<usize>,
+    /// Number of times the user's photos have been downloaded (only when
+    /// getting a single user).
+    pub downloads: Option<usize>,
+}
+
+/// A cut-down user, as returned alongside a photo which was liked or added to
+/// a collection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserSummary {
+    /// User ID.
+    pub id: String,
+    /// Username.
+    pub username: String,
+    /// User's real name.
+    pub name: String,
+    /// Links to the user's profile.
+    pub links: UserSummaryLinks,
+}
+
+/// Links to pages about a cut-down user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserSummaryLinks {
+    /// Link to the user in the user endpoint.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// Link to the user's profile
+    pub html: String,
+    /// API link to the user's photos.
+    pub photos: String,
+    /// API link to the user's likes.
+    pub likes: String,
+}
+
+/// A badge shown on a user's profile.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Badge {
+    /// Title of the badge.
+    pub title: String,
+    /// Is this the user's primary badge.
+    pub primary: bool,
+    /// Slug of the badge.
+    pub slug: String,
+    /// Link to the page about the badge.
+    pub link: Option<String>,
+}
+
+/// Tags describing a user's photos.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTags {
+    /// Tags chosen by the user.
+    pub custom: Vec<Tag>,
+    /// Tags aggregated from the user's photos.
+    pub aggregated: Vec<Tag>,
 }
 
 /// A user's profile images
@@ -77,8 +143,12 @@ pub struct UserLinks {
 }
 
 /// Session type for handling user updates
-#[derive(Debug, Default, Serialize, Deserialize)]
-pub struct UserUpdate {
+#[derive(Debug, Serialize)]
+pub struct UserUpdate<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    bearer: &'a Bearer,
     username: Option<String>,
     first_name: Option<String>,
     last_name: Option<String>,
@@ -89,10 +159,21 @@ pub struct UserUpdate {
     instagram_username: Option<String>,
 }
 
-impl Me {
+impl<'a, C> Me<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, bearer: &'a Bearer) -> Self {
+        Me { client, bearer }
+    }
+
     /// Gets the user data of the current user
     ///
+    /// Only the public scope is needed, but private data, such as the user's
+    /// email, is only included if the bearer token grants read_user.
+    ///
     /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant public.
     /// - Request wrapping a Hyper error is raised if there is an error
     /// handling the HTTP Stream.
     /// - MalformedResponse
@@ -100,18 +181,36 @@ impl Me {
     /// Unsplash is invalid.
     ///     - wrapping an IO error is raised if an IO
     /// error occurs.
-    pub fn get<C>(self, client: &Client<C>, bearer: &str) -> impl Future<Item = User, Error = Error>
-    where
-        C: Connect + 'static,
-    {
-        ::endpoint::get((), client, format!("Bearer {}", bearer).as_ref(), ME_URI.clone())
+    pub fn get(self) -> impl Future<Item = User, Error = Error> {
+        match self.bearer.auth(Scope::Public) {
+            Ok(auth) => {
+                Either::A(::endpoint::get(&(), self.client, &auth, self.client.url(ME_PATH)))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
     }
 
     /// Update the current user's information.
-    pub fn update(self) -> UserUpdate { UserUpdate::default() }
+    pub fn update(self) -> UserUpdate<'a, C> {
+        UserUpdate {
+            client: self.client,
+            bearer: self.bearer,
+            username: None,
+            first_name: None,
+            last_name: None,
+            email: None,
+            url: None,
+            location: None,
+            bio: None,
+            instagram_username: None,
+        }
+    }
 }
 
-impl UserUpdate {
+impl<'a, C> UserUpdate<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Update the user's username.
     pub fn username(mut self, username: String) -> Self {
         self.username.replace(username);
@@ -163,18 +262,18 @@ impl UserUpdate {
     /// Update the user's profile.
     ///
     /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant write_user.
     /// - Request wrapping a Hyper error is raised if there is an error
     /// handling the HTTP Stream. - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn update<C>(
-        self,
-        client: &Client<C>,
-        bearer: &str,
-    ) -> impl Future<Item = User, Error = Error>
-    where
-        C: Connect + 'static,
-    {
-        ::endpoint::put(self, client, format!("Bearer {}", bearer).as_ref(), ME_URI.clone())
+    pub fn update(self) -> impl Future<Item = User, Error = Error> {
+        let client = self.client;
+        match self.bearer.auth(Scope::WriteUser) {
+            Ok(auth) => {
+                Either::A(::endpoint::put(&self, client, &auth, client.url(ME_PATH)))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
     }
 }
@@ -1,18 +1,38 @@
+/// Collections endpoint.
+pub mod collections;
 /// Me endpoint.
 pub mod me;
 /// Photos endpoint.
 pub mod photos;
+/// Search endpoint.
+pub mod search;
+/// Stats endpoint.
+pub mod stats;
+/// Topics endpoint.
+pub mod topics;
+/// Users endpoint.
+pub mod users;
+
+mod page;
 
 use failure::Fail;
-use futures::{Future, Stream};
-use hyper::{client::connect::Connect, Client, Method, Request, StatusCode, Uri};
+use futures::{future::Either, Future, Stream};
+use hyper::{client::connect::Connect,
+            header::{IF_RANGE, RANGE},
+            Body, HeaderMap, Method, Request, Response, StatusCode};
 use itertools::Itertools;
-use serde::{de::DeserializeOwned, ser::Serialize};
+use serde::{de::{DeserializeOwned, IgnoredAny},
+            ser::Serialize};
 
 use std::{error::Error as StdError, fmt};
 
+use auth::Auth;
+use client::Unsplash;
 use error::*;
 
+use self::page::Links;
+pub use self::page::{Cursor, Page};
+
 /// A trait to define how to convert a type into a GET Query String.
 /// A blanket impl is provided for all Serializable types.
 pub trait ToQuery {
@@ -68,85 +88,161 @@ where
 }
 
 /// Used to parse JSON into any serializable type.
+/// An empty body (i.e. 204 No Content) is parsed as null, so it can be parsed
+/// into ().
 fn parse_data<T>(v: Vec<u8>) -> ::futures::future::FutureResult<T, Error>
 where
     T: DeserializeOwned,
 {
+    let v = if v.is_empty() { b"null".to_vec() } else { v };
     match ::serde_json::from_slice::<T>(&v) {
         Ok(j) => ::futures::future::ok(j),
         Err(e) => ::futures::future::err(Error::from(e.context(ErrorKind::MalformedResponse))),
     }
 }
 
+/// Used to discard a response whose content isn't needed, which may be empty
+/// or any JSON.
+fn ignore(_: IgnoredAny) {}
+
 /// Convenience method for performing a GET request to Unsplash, determining if
 /// an error occur and returning a Future to represent this.
 ///
 
-fn get<T, C, R>(
-    query: T,
-    client: &Client<C>,
-    auth: &str,
-    uri: Uri,
+fn get<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
 ) -> impl Future<Item = R, Error = Error>
 where
-    T: Serialize,
     C: Connect + 'static,
     R: DeserializeOwned,
 {
     request(query, client, auth, uri, Method::GET)
 }
 
-fn put<T, C, R>(
-    query: T,
-    client: &Client<C>,
-    auth: &str,
-    uri: Uri,
+fn put<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
 ) -> impl Future<Item = R, Error = Error>
 where
-    T: Serialize,
     C: Connect + 'static,
     R: DeserializeOwned,
 {
     request(query, client, auth, uri, Method::PUT)
 }
 
-fn delete<T, C, R>(
-    query: T,
-    client: &Client<C>,
-    auth: &str,
-    uri: Uri,
+fn delete<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
 ) -> impl Future<Item = R, Error = Error>
 where
-    T: Serialize,
     C: Connect + 'static,
     R: DeserializeOwned,
 {
     request(query, client, auth, uri, Method::DELETE)
 }
 
-fn post<T, C, R>(
-    query: T,
-    client: &Client<C>,
-    auth: &str,
-    uri: Uri,
+pub(crate) fn post<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
 ) -> impl Future<Item = R, Error = Error>
 where
-    T: Serialize,
     C: Connect + 'static,
     R: DeserializeOwned,
 {
     request(query, client, auth, uri, Method::POST)
 }
 
-fn request<T, C, R>(
-    query: T,
-    client: &Client<C>,
-    auth: &str,
-    uri: Uri,
+/// Convenience method for lazily performing a GET request for each page of the
+/// paged endpoint at path, starting from page or the first, and returning a
+/// Stream of the elements of every page.
+///
+/// The query must not hold the page, as it is added to each request. Each page
+/// is followed by the page its Link header points to as next, until there is
+/// no next page, or a page is empty. Only the number of the next page is taken
+/// from the Link header, so every page is requested from the same root URI as
+/// the first.
+fn paginate<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    path: &str,
+    page: Option<usize>,
+) -> impl Stream<Item = R::Item, Error = Error>
+where
+    C: Connect + 'static,
+    R: DeserializeOwned + IntoIterator,
+{
+    let uri = format!("{}{}", client.url(path), query.to_query());
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    let auth = client.auth();
+    let client = client.clone();
+
+    ::futures::stream::unfold(Some(page.unwrap_or(1)), move |page| {
+        let uri = format!("{}{}page={}", uri, separator, page?);
+        debug!("requesting page {}", uri);
+        Some(request_with_headers::<_, R>(&(), &client, &auth, uri, Method::GET).map(
+            |(headers, items)| {
+                let page = Page::new(items.into_iter().collect(), &headers);
+                let next = match page.next {
+                    Some(ref next) if !page.items.is_empty() => Some(next.page),
+                    _ => None,
+                };
+                (::futures::stream::iter_ok(page.items), next)
+            },
+        ))
+    })
+    .flatten()
+}
+
+/// Convenience method for performing a GET request to a paged endpoint,
+/// returning a Future of the page, along with the links to other pages
+/// parsed from the response's headers.
+fn get_page<C, T>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
+) -> impl Future<Item = Page<T>, Error = Error>
+where
+    C: Connect + 'static,
+    T: DeserializeOwned,
+{
+    request_with_headers(query, client, auth, uri, Method::GET)
+        .map(|(headers, items)| Page::new(items, &headers))
+}
+
+fn request<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
     method: Method,
 ) -> impl Future<Item = R, Error = Error>
 where
-    T: Serialize,
+    C: Connect + 'static,
+    R: DeserializeOwned,
+{
+    request_with_headers(query, client, auth, uri, method).map(|(_, data)| data)
+}
+
+/// Performs the request, returning a Future of the headers of the response
+/// along with its parsed body.
+fn request_with_headers<C, R>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    auth: &Auth,
+    uri: String,
+    method: Method,
+) -> impl Future<Item = (HeaderMap, R), Error = Error>
+where
     C: Connect + 'static,
     R: DeserializeOwned,
 {
@@ -156,30 +252,129 @@ where
         .uri(format!("{}{}", uri, query.to_query()))
         .header("Accept", "application/json")
         .header("Accept-Version", "v1")
-        .header("Authorization", auth)
-        .body(::hyper::Body::empty())
-        .unwrap();
-    trace!("request: {:?}", request);
+        .header("Authorization", auth.header())
+        .body(Body::empty());
+    send(client, request)
+}
+
+/// Convenience method for performing a POST request with the form as the
+/// body, rather than the query, and without any credentials.
+///
+/// Used to send secrets, which would otherwise be exposed in the URI.
+pub(crate) fn post_form<C, R>(
+    form: &dyn ToQuery,
+    client: &Unsplash<C>,
+    uri: String,
+) -> impl Future<Item = R, Error = Error>
+where
+    C: Connect + 'static,
+    R: DeserializeOwned,
+{
+    debug!("generating form request");
+    let request = Request::post(uri)
+        .header("Accept", "application/json")
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(Body::from(form.to_query().trim_start_matches('?').to_owned()));
+    send(client, request).map(|(_, data)| data)
+}
 
-    client.request(request).map_err(move |e| Error::from(e.context(ErrorKind::Request))).and_then(
-        |res| {
-            debug!("status code: {}", res.status());
-            trace!("response: {:?}", res);
-            let parser = if res.status().is_success() { parse_data::<R> } else { parse_err };
-            let status = res.status().as_u16();
-
-            res.into_body()
-                .map_err(|e| Error::from(e.context(ErrorKind::MalformedResponse)))
-                .fold(Vec::new(), fold)
-                .and_then(parser)
-                .map_err(move |e| {
-                    if status == StatusCode::FORBIDDEN.as_u16() {
-                        Error::from(e.context(ErrorKind::Forbidden))
-                    } else {
-                        e
-                    }
-                })
+/// Sends the request, returning a Future of the headers of the response along
+/// with its parsed body.
+fn send<C, R>(
+    client: &Unsplash<C>,
+    request: Result<Request<Body>, ::hyper::http::Error>,
+) -> impl Future<Item = (HeaderMap, R), Error = Error>
+where
+    C: Connect + 'static,
+    R: DeserializeOwned,
+{
+    let request = match request {
+        Ok(request) => request,
+        Err(e) => {
+            return Either::B(::futures::future::err(Error::from(e.context(ErrorKind::Request))))
         },
+    };
+    // Only the method and URI are logged, as the headers and body hold secrets.
+    trace!("request: {} {}", request.method(), request.uri());
+
+    Either::A(
+        client
+            .http()
+            .request(request)
+            .map_err(move |e| Error::from(e.context(ErrorKind::Request)))
+            .and_then(|res| {
+                debug!("status code: {}", res.status());
+                trace!("response: {:?}", res);
+                let parser = if res.status().is_success() { parse_data::<R> } else { parse_err };
+                let status = res.status().as_u16();
+                let (parts, body) = res.into_parts();
+
+                body.map_err(|e| Error::from(e.context(ErrorKind::MalformedResponse)))
+                    .fold(Vec::new(), fold)
+                    .and_then(parser)
+                    .map_err(move |e| {
+                        if status == StatusCode::FORBIDDEN.as_u16() {
+                            Error::from(e.context(ErrorKind::Forbidden))
+                        } else {
+                            e
+                        }
+                    })
+                    .map(move |data| (parts.headers, data))
+            }),
+    )
+}
+
+/// Convenience method for performing a GET request for raw data (i.e. an
+/// image) rather than JSON, determining if an error occurred and returning a
+/// Future of the response, whose body is yet to be read.
+///
+/// If offset is not 0, only the data from offset onwards is requested, as long
+/// as the data still matches if_range, if given. The caller must check the
+/// range returned, and a 416 response is returned rather than raised, so the
+/// caller can check the length of the data.
+pub(crate) fn get_raw<C>(
+    client: &Unsplash<C>,
+    uri: &str,
+    offset: u64,
+    if_range: Option<&str>,
+) -> impl Future<Item = Response<Body>, Error = Error>
+where
+    C: Connect + 'static,
+{
+    debug!("generating raw request");
+    let mut builder = Request::get(uri);
+    if offset != 0 {
+        builder.header(RANGE, format!("bytes={}-", offset));
+        if let Some(if_range) = if_range {
+            builder.header(IF_RANGE, if_range);
+        }
+    }
+    let request = match builder.body(Body::empty()) {
+        Ok(request) => request,
+        Err(e) => {
+            return Either::B(::futures::future::err(Error::from(e.context(ErrorKind::Request))))
+        },
+    };
+    trace!("request: {:?}", request);
+
+    Either::A(
+        client
+            .http()
+            .request(request)
+            .map_err(move |e| Error::from(e.context(ErrorKind::Request)))
+            .and_then(move |res| {
+                debug!("status code: {}", res.status());
+                trace!("response: {:?}", res);
+                if res.status().is_success()
+                    || (offset != 0 && res.status() == StatusCode::RANGE_NOT_SATISFIABLE)
+                {
+                    Ok(res)
+                } else if res.status() == StatusCode::FORBIDDEN {
+                    Err(Error::from(ErrorKind::Forbidden))
+                } else {
+                    Err(Error::from(ErrorKind::UnexpectedStatus(res.status().as_u16())))
+                }
+            }),
     )
 }
 
@@ -189,3 +384,60 @@ fn fold(mut v: Vec<u8>, chunk: ::hyper::Chunk) -> ::futures::future::Ok<Vec<u8>,
     v.extend(&chunk[..]);
     ::futures::future::ok(v)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::{header::LINK, service::service_fn, Client, Server};
+    use tokio::runtime::Runtime;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Serialize)]
+    struct Query {
+        order_by: &'static str,
+    }
+
+    #[test]
+    fn paginate_follows_next_page_through_api_url() {
+        let mut rt = Runtime::new().unwrap();
+        let uris = Arc::new(Mutex::new(Vec::new()));
+        let server_uris = uris.clone();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(move || {
+            let uris = server_uris.clone();
+            service_fn(move |req: Request<Body>| {
+                let uri = req.uri().to_string();
+                uris.lock().unwrap().push(uri.clone());
+                let res = if uri.ends_with("page=1") {
+                    Response::builder()
+                        .header(
+                            LINK,
+                            "<https://api.unsplash.com/photos?order_by=latest&page=2>; \
+                             rel=\"next\"",
+                        )
+                        .body(Body::from("[1, 2]"))
+                } else {
+                    Response::builder().body(Body::from("[3]"))
+                };
+                ::futures::future::result(res)
+            })
+        });
+        let addr = server.local_addr();
+        rt.spawn(server.map_err(|e| panic!("{}", e)));
+
+        let client = Unsplash::new(Client::new(), "access key".to_owned())
+            .api_url(format!("http://{}/", addr));
+        let query = Query { order_by: "latest" };
+        let items = rt
+            .block_on(
+                paginate::<_, Vec<u32>>(&query, &client, "photos", None).collect(),
+            )
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(
+            *uris.lock().unwrap(),
+            vec!["/photos?order_by=latest&page=1", "/photos?order_by=latest&page=2"]
+        );
+    }
+}
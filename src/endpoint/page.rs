@@ -0,0 +1,217 @@
+use hyper::header::{HeaderMap, LINK};
+
+/// A page of elements from a paged endpoint.
+///
+/// Along with the elements, Unsplash reports how many elements there are in
+/// total and links to the pages around this one, which can be jumped to by
+/// passing a cursor's page to the endpoint's page method.
+///
+/// Paged endpoints can also stream the elements of every page, in which case
+/// pages are requested lazily, from the one given to the endpoint's page method
+/// or the first, until there are no more elements. Use Stream::take to cap the
+/// number of elements, as no more pages are requested once it is reached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    /// The elements on this page.
+    pub items: Vec<T>,
+    /// Total number of elements over every page, if Unsplash reported it.
+    pub total: Option<usize>,
+    /// Number of elements per page, if Unsplash reported it.
+    pub per_page: Option<usize>,
+    /// The next page, unless this is the last page.
+    pub next: Option<Cursor>,
+    /// The previous page, unless this is the first page.
+    pub prev: Option<Cursor>,
+    /// The first page, unless this is the first page.
+    pub first: Option<Cursor>,
+    /// The last page, unless this is the last page.
+    pub last: Option<Cursor>,
+}
+
+/// A link to a page of a paged endpoint.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Cursor {
+    /// Number of the page, starting at 1.
+    pub page: usize,
+    /// API link to the page.
+    pub url: String,
+}
+
+/// Links to the pages around a page, parsed from the Link headers of a
+/// response.
+#[derive(Debug, Default)]
+pub(crate) struct Links {
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+    pub first: Option<Cursor>,
+    pub last: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    /// Create a page of the given elements, parsing the total, the number of
+    /// elements per page and the links to other pages from the headers of
+    /// the response.
+    pub(crate) fn new(items: Vec<T>, headers: &HeaderMap) -> Self {
+        let links = Links::new(headers);
+        Page {
+            items,
+            total: header(headers, "X-Total"),
+            per_page: header(headers, "X-Per-Page"),
+            next: links.next,
+            prev: links.prev,
+            first: links.first,
+            last: links.last,
+        }
+    }
+
+    /// Total number of pages, if Unsplash reported the total and the number of
+    /// elements per page.
+    pub fn total_pages(&self) -> Option<usize> {
+        match (self.total, self.per_page) {
+            (Some(total), Some(per_page)) if per_page != 0 => {
+                Some((total + per_page - 1) / per_page)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Links {
+    /// Parse the links to other pages from the headers of a response.
+    pub(crate) fn new(headers: &HeaderMap) -> Self {
+        let mut links = Links::default();
+
+        for link in headers.get_all(LINK).iter().filter_map(|link| link.to_str().ok()) {
+            for (rel, cursor) in parse_link(link) {
+                match rel {
+                    "next" => links.next = Some(cursor),
+                    "prev" => links.prev = Some(cursor),
+                    "first" => links.first = Some(cursor),
+                    "last" => links.last = Some(cursor),
+                    _ => trace!("ignoring link to {} page", rel),
+                }
+            }
+        }
+
+        links
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type IntoIter = ::std::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter { self.items.into_iter() }
+}
+
+/// Parse a numeric header, if it is present and valid.
+fn header(headers: &HeaderMap, name: &str) -> Option<usize> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+/// Parse a Link header, i.e. `<url>; rel="next", <url>; rel="last"`, into the
+/// relation and cursor of each link.
+///
+/// Links without a relation are skipped. Links without a page query are taken
+/// to be the first page.
+fn parse_link(link: &str) -> Vec<(&str, Cursor)> {
+    link.split('<')
+        .skip(1)
+        .filter_map(|link| {
+            let end = link.find('>')?;
+            let url = &link[..end];
+            let rel = link[end + 1..]
+                .split(';')
+                .filter_map(|param| {
+                    let mut param = param.trim().trim_end_matches(',').splitn(2, '=');
+                    match (param.next()?.trim(), param.next()) {
+                        ("rel", Some(rel)) => Some(rel.trim().trim_matches('"')),
+                        _ => None,
+                    }
+                })
+                .next()?;
+            let page = url
+                .splitn(2, '?')
+                .nth(1)
+                .and_then(|query| query.split('&').find(|param| param.starts_with("page=")))
+                .and_then(|param| param[5..].parse().ok())
+                .unwrap_or(1);
+
+            Some((rel, Cursor { page, url: url.to_owned() }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(page: usize, url: &str) -> Cursor { Cursor { page, url: url.to_owned() } }
+
+    #[test]
+    fn multiple_rels() {
+        let link = "<https://api.unsplash.com/photos?page=1>; rel=\"first\", \
+                    <https://api.unsplash.com/photos?page=2>; rel=\"prev\", \
+                    <https://api.unsplash.com/photos?page=40>; rel=\"last\", \
+                    <https://api.unsplash.com/photos?page=4>; rel=\"next\"";
+        assert_eq!(
+            parse_link(link),
+            vec![
+                ("first", cursor(1, "https://api.unsplash.com/photos?page=1")),
+                ("prev", cursor(2, "https://api.unsplash.com/photos?page=2")),
+                ("last", cursor(40, "https://api.unsplash.com/photos?page=40")),
+                ("next", cursor(4, "https://api.unsplash.com/photos?page=4")),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_page() {
+        let link = "<https://api.unsplash.com/photos?order_by=latest>; rel=\"first\"";
+        assert_eq!(
+            parse_link(link),
+            vec![("first", cursor(1, "https://api.unsplash.com/photos?order_by=latest"))]
+        );
+
+        let link = "<https://api.unsplash.com/photos>; rel=\"first\"";
+        assert_eq!(parse_link(link), vec![("first", cursor(1, "https://api.unsplash.com/photos"))]);
+    }
+
+    #[test]
+    fn per_page_before_page() {
+        let link = "<https://api.unsplash.com/photos?per_page=30&page=3>; rel=\"next\"";
+        assert_eq!(
+            parse_link(link),
+            vec![("next", cursor(3, "https://api.unsplash.com/photos?per_page=30&page=3"))]
+        );
+    }
+
+    #[test]
+    fn missing_rel() {
+        let link = "<https://api.unsplash.com/photos?page=2>; title=\"next\", \
+                    <https://api.unsplash.com/photos?page=5>; rel=\"last\"";
+        assert_eq!(
+            parse_link(link),
+            vec![("last", cursor(5, "https://api.unsplash.com/photos?page=5"))]
+        );
+    }
+
+    #[test]
+    fn page() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Total", "397".parse().unwrap());
+        headers.insert("X-Per-Page", "10".parse().unwrap());
+        headers.insert(
+            LINK,
+            "<https://api.unsplash.com/photos?page=2>; rel=\"next\"".parse().unwrap(),
+        );
+
+        let page = Page::new(vec![1, 2, 3], &headers);
+        assert_eq!(page.items, vec![1, 2, 3]);
+        assert_eq!(page.total, Some(397));
+        assert_eq!(page.per_page, Some(10));
+        assert_eq!(page.total_pages(), Some(40));
+        assert_eq!(page.next, Some(cursor(2, "https://api.unsplash.com/photos?page=2")));
+        assert_eq!(page.prev, None);
+    }
+}
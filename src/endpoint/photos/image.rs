@@ -0,0 +1,252 @@
+use itertools::Itertools;
+
+use std::fmt;
+
+use super::Urls;
+use endpoint::ToQuery;
+
+/// Builder for creating a URL to a photo resized, cropped or converted by
+/// Unsplash.
+///
+/// Built from the raw URL of a photo, through
+/// [Urls::image](struct.Urls.html#method.image).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImageUrl {
+    #[serde(skip)]
+    raw: String,
+    w: Option<usize>,
+    h: Option<usize>,
+    fit: Option<Fit>,
+    crop: Option<String>,
+    fm: Option<Format>,
+    q: Option<u8>,
+    dpr: Option<f32>,
+    auto: Option<Auto>,
+}
+
+/// How a photo is fit to the requested width and height.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Fit {
+    /// Resize to fit, then fill any space by extending the edges.
+    Clamp,
+    /// Resize to fit, without cropping or distorting.
+    Clip,
+    /// Resize to fill, cropping any excess.
+    Crop,
+    /// Crop to the faces in the photo.
+    FaceArea,
+    /// Resize to fit, then fill any space with a background color.
+    Fill,
+    /// Resize to fit without enlarging, then fill any space with a background
+    /// color.
+    FillMax,
+    /// Resize to fit, without enlarging.
+    Max,
+    /// Resize to fill without enlarging, cropping any excess.
+    Min,
+    /// Resize to exactly fill, distorting the photo.
+    Scale,
+}
+
+/// Which part of a photo is kept when it is cropped.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Crop {
+    /// Keep the top.
+    Top,
+    /// Keep the bottom.
+    Bottom,
+    /// Keep the left.
+    Left,
+    /// Keep the right.
+    Right,
+    /// Keep any faces.
+    Faces,
+    /// Keep the focal point.
+    FocalPoint,
+    /// Keep the most detailed edges.
+    Edges,
+    /// Keep the most detailed area.
+    Entropy,
+}
+
+/// Format of a photo.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// JPEG format.
+    Jpg,
+    /// PNG format.
+    Png,
+    /// WebP format.
+    Webp,
+    /// AVIF format.
+    Avif,
+}
+
+/// Automatic optimisations of a photo.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Auto {
+    /// Use the best format the browser supports.
+    Format,
+}
+
+impl ImageUrl {
+    /// Create a builder from the raw URL of a photo.
+    pub fn new(raw: String) -> Self {
+        ImageUrl {
+            raw,
+            w: None,
+            h: None,
+            fit: None,
+            crop: None,
+            fm: None,
+            q: None,
+            dpr: None,
+            auto: None,
+        }
+    }
+
+    /// Specify the width of the photo in pixels.
+    ///
+    /// # Panics
+    /// Panics if w is 0.
+    pub fn w(mut self, w: usize) -> Self {
+        assert_ne!(w, 0, "Cannot have a width of 0!");
+        self.w.replace(w);
+        self
+    }
+
+    /// Specify the height of the photo in pixels.
+    ///
+    /// # Panics
+    /// Panics if h is 0.
+    pub fn h(mut self, h: usize) -> Self {
+        assert_ne!(h, 0, "Cannot have a height of 0!");
+        self.h.replace(h);
+        self
+    }
+
+    /// Specify how the photo is fit to the width and height.
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit.replace(fit);
+        self
+    }
+
+    /// Specify which parts of the photo to keep when it is cropped, in order of
+    /// priority.
+    ///
+    /// If no parts are given, the crop is left unchanged.
+    pub fn crop<I>(mut self, crop: I) -> Self
+    where
+        I: IntoIterator<Item = Crop>,
+    {
+        let crop = crop.into_iter().join(",");
+        if !crop.is_empty() {
+            self.crop.replace(crop);
+        }
+        self
+    }
+
+    /// Specify the format of the photo.
+    pub fn fm(mut self, fm: Format) -> Self {
+        self.fm.replace(fm);
+        self
+    }
+
+    /// Specify the quality of the photo, for lossy formats.
+    ///
+    /// # Panics
+    /// Panics if q is greater than 100.
+    pub fn q(mut self, q: u8) -> Self {
+        assert!(q <= 100, "Quality must be between 0 and 100!");
+        self.q.replace(q);
+        self
+    }
+
+    /// Specify the device pixel ratio the photo is displayed at, i.e. 1.5.
+    ///
+    /// # Panics
+    /// Panics if dpr is not between 1 and 5.
+    pub fn dpr(mut self, dpr: f32) -> Self {
+        assert!(dpr >= 1.0 && dpr <= 5.0, "Device pixel ratio must be between 1 and 5!");
+        self.dpr.replace(dpr);
+        self
+    }
+
+    /// Use the best format the browser supports, in place of the format.
+    pub fn auto_format(mut self) -> Self {
+        self.auto.replace(Auto::Format);
+        self
+    }
+}
+
+impl Urls {
+    /// Create a builder for a URL to this photo resized, cropped or converted
+    /// by Unsplash.
+    pub fn image(&self) -> ImageUrl { ImageUrl::new(self.raw.clone()) }
+}
+
+impl fmt::Display for ImageUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let query = self.to_query();
+        f.write_str(&self.raw)?;
+        if query.is_empty() {
+            Ok(())
+        } else if self.raw.contains('?') {
+            // The raw URL already has a query, which the parameters extend.
+            write!(f, "&{}", &query[1..])
+        } else {
+            f.write_str(&query)
+        }
+    }
+}
+
+impl fmt::Display for Crop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match *self {
+            Crop::Top => "top",
+            Crop::Bottom => "bottom",
+            Crop::Left => "left",
+            Crop::Right => "right",
+            Crop::Faces => "faces",
+            Crop::FocalPoint => "focalpoint",
+            Crop::Edges => "edges",
+            Crop::Entropy => "entropy",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A raw URL, which always has a query.
+    const RAW: &str = "https://images.unsplash.com/photo-1417325384643-aac51acc9e5d?ixid=\
+                       MnwxfDB8MXxyYW5kb218MHx8fHx8fHx8MTYxNjc3ODgyNA";
+
+    #[test]
+    fn extends_raw_query() {
+        let url = ImageUrl::new(RAW.to_owned())
+            .w(400)
+            .fit(Fit::Crop)
+            .crop(vec![Crop::Faces, Crop::Edges])
+            .dpr(1.5);
+        assert_eq!(url.to_string(), format!("{}&w=400&fit=crop&crop=faces%2Cedges&dpr=1.5", RAW));
+    }
+
+    #[test]
+    fn raw_without_query() {
+        let raw = "https://images.unsplash.com/photo-1417325384643-aac51acc9e5d";
+        assert_eq!(ImageUrl::new(raw.to_owned()).to_string(), raw);
+        assert_eq!(ImageUrl::new(raw.to_owned()).q(80).to_string(), format!("{}?q=80", raw));
+    }
+
+    #[test]
+    fn empty_crop() {
+        let url = ImageUrl::new(RAW.to_owned()).crop(Vec::new());
+        assert_eq!(url.to_string(), RAW);
+    }
+}
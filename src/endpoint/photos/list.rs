@@ -1,22 +1,32 @@
-use hyper::{client::connect::Connect, rt::Future, Client, Uri};
+use client::Unsplash;
+use endpoint::Page;
+use hyper::{client::connect::Connect,
+            rt::{Future, Stream}};
 
 use super::{Order, Photo};
 use error::*;
 
-lazy_static! {
-    /// URI of the endpoint to get a list of photos from Unsplash.
-    pub static ref LIST_URI: Uri = format!("{}{}", ::API_URL, "photos").parse().unwrap();
-}
+/// Path of the endpoint to get a list of photos from Unsplash.
+pub const LIST_PATH: &str = "photos";
 
 /// Request builder for creating a List request.
-#[derive(Debug, Default, Serialize, Copy, Clone)]
-pub struct List {
+#[derive(Debug, Serialize, Clone)]
+pub struct List<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
     page: Option<usize>,
     per_page: Option<usize>,
     order_by: Option<Order>,
 }
 
-impl List {
+impl<'a, C> List<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self {
+        List { client, page: None, per_page: None, order_by: None }
+    }
+
     /// Specify which page to access.
     ///
     /// Unsplash uses pagination.
@@ -24,7 +34,7 @@ impl List {
     /// # Panics
     /// Panics if page is 0.
     pub fn page(mut self, page: usize) -> Self {
-        assert_eq!(0, page, "Pages start a 1, not 0!");
+        assert_ne!(0, page, "Pages start a 1, not 0!");
         self.page.replace(page);
         self
     }
@@ -36,7 +46,7 @@ impl List {
     /// # Panics
     /// Panics if per_page is 0.
     pub fn per_page(mut self, per_page: usize) -> Self {
-        assert_eq!(0, per_page, "Cannot have 0 elements per page!");
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
         self.per_page.replace(per_page);
         self
     }
@@ -57,19 +67,22 @@ impl List {
     /// Unsplash is invalid.
     ///     - wrapping an IO error is raised if an IO
     /// error occurs.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Vec<Photo>, Error = Error>
-    where
-        C: Connect + 'static,
-    {
-        ::endpoint::get(
-            self,
-            client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            LIST_URI.clone(),
-        )
+    pub fn get(self) -> impl Future<Item = Page<Photo>, Error = Error> {
+        let client = self.client;
+        ::endpoint::get_page(&self, client, &client.auth(), client.url(LIST_PATH))
+    }
+
+    /// Stream all the photos, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Photo, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, Vec<Photo>>(&self, self.client, LIST_PATH, page)
     }
 }
@@ -1,31 +1,90 @@
 //! Photos endpoint
 //!
-//! Access to the endpoint is through the [Photo](struct.Photos.html) struct.
+//! Access to the endpoint is through the [Photos](struct.Photos.html) struct,
+//! which is created by [Unsplash::photos](../../client/struct.Unsplash.html#method.photos).
 
+use auth::Bearer;
 use chrono::{DateTime, FixedOffset};
-use endpoint::me::User;
-use futures::Future;
-use hyper::{client::connect::Connect, Client};
+use client::Unsplash;
+use endpoint::me::{User, UserSummary};
+use failure::Fail;
+use futures::{future::Either, Future, Stream};
+use hyper::{client::connect::Connect, Chunk};
+use oauth::Scope;
 
 use std::fmt;
 
+mod image;
 mod list;
 mod random;
+mod save;
+mod statistics;
+mod update;
 
 use error::*;
 
-pub use self::{list::List, random::Random};
+pub use endpoint::collections::Collection;
+
+pub use self::{image::{Crop, Fit, Format, ImageUrl},
+               list::List,
+               random::Random,
+               save::Progress,
+               statistics::{DataPoint, Historical, PhotoStatistics, Resolution, Statistic,
+                            Statistics},
+               update::PhotoUpdate};
 
 /// Access type to Unsplash's Photos endpoint.
-#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
-pub struct Photos;
+#[derive(Debug, Clone)]
+pub struct Photos<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
 
-/// A type for a url returned from a photo's download endpoint.
+/// A type for a url returned from a photo's download endpoint or a user's
+/// portfolio endpoint.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Url {
     url: String,
 }
 
+/// A photo and user returned from liking or unliking a photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Like {
+    /// The photo which was liked or unliked.
+    pub photo: PhotoSummary,
+    /// The user who liked or unliked the photo.
+    pub user: UserSummary,
+}
+
+/// A cut-down photo, as returned from liking a photo or adding it to a
+/// collection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PhotoSummary {
+    /// Photo ID.
+    pub id: String,
+    /// Number of likes the photo has
+    pub likes: usize,
+    /// Has the photo been liked by the current user.
+    pub liked_by_user: bool,
+    /// Description of the photo.
+    pub description: Option<String>,
+    /// Urls to the photo in various sizes.
+    pub urls: Urls,
+    /// Links to the photo.
+    pub links: PhotoSummaryLinks,
+}
+
+/// Links to a cut-down photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PhotoSummaryLinks {
+    /// API link to the photo.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// Link to the photo.
+    pub html: String,
+    /// Link to download the photo.
+    pub download: String,
+}
+
 /// A Photo from Unsplash.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Photo {
@@ -55,21 +114,80 @@ pub struct Photo {
     pub urls: Urls,
     /// Links to the photo.
     pub links: PhotoLinks,
+    /// Number of times the photo has been viewed (only when getting a single
+    /// photo).
+    pub views: Option<usize>,
+    /// Number of times the photo has been downloaded (only when getting a
+    /// single photo).
+    pub downloads: Option<usize>,
+    /// EXIF data of the photo (only when getting a single photo).
+    pub exif: Option<Exif>,
+    /// Where the photo was taken (only when getting a single photo).
+    pub location: Option<Location>,
+    /// Tags describing the photo (only when getting a single photo).
+    pub tags: Option<Vec<Tag>>,
+    /// Collections related to the photo (only when getting a single photo).
+    pub related_collections: Option<RelatedCollections>,
+    /// Statistics of the photo (only when listing a user's photos with their
+    /// statistics).
+    pub statistics: Option<PhotoStatistics>,
+}
+
+/// EXIF data of a photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Exif {
+    /// Make of the camera.
+    pub make: Option<String>,
+    /// Model of the camera.
+    pub model: Option<String>,
+    /// Exposure time, i.e. 1/100.
+    pub exposure_time: Option<String>,
+    /// Aperture, i.e. 4.0.
+    pub aperture: Option<String>,
+    /// Focal length, i.e. 50.0.
+    pub focal_length: Option<String>,
+    /// ISO speed.
+    pub iso: Option<usize>,
 }
 
-/// A collection of photos on Unsplsash
+/// Where a photo was taken.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct Collection {
-    /// Collection ID
-    pub id: usize,
-    /// Collection's title
+pub struct Location {
+    /// Title of the location.
+    pub title: Option<String>,
+    /// Name of the location.
+    pub name: Option<String>,
+    /// City of the location.
+    pub city: Option<String>,
+    /// Country of the location.
+    pub country: Option<String>,
+    /// Coordinates of the location.
+    pub position: Option<Position>,
+}
+
+/// Coordinates of a location.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    /// Latitude of the location.
+    pub latitude: Option<f64>,
+    /// Longitude of the location.
+    pub longitude: Option<f64>,
+}
+
+/// A tag describing a photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tag {
+    /// Title of the tag.
     pub title: String,
-    /// Date when collection was published.
-    pub published_at: DateTime<FixedOffset>,
-    /// Last date when the collection was updated.
-    pub updated_at: DateTime<FixedOffset>,
-    /// Is the collection curated.
-    pub curated: bool,
+}
+
+/// Collections related to a photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelatedCollections {
+    /// Total number of related collections.
+    pub total: usize,
+    /// The related collections returned.
+    pub results: Vec<Collection>,
 }
 
 /// Urls of a photo in various sizes.
@@ -103,6 +221,7 @@ pub struct PhotoLinks {
 
 /// Ordering of results from Unsplash
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Order {
     /// Latest comes first.
     /// Default if unspecified.
@@ -115,6 +234,7 @@ pub enum Order {
 
 /// Orientation of a photo
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Orientation {
     /// Portrait orientation.
     Portrait,
@@ -124,6 +244,45 @@ pub enum Orientation {
     Squarish,
 }
 
+/// Color of a photo
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Color {
+    /// Black and white.
+    BlackAndWhite,
+    /// Black.
+    Black,
+    /// White.
+    White,
+    /// Yellow.
+    Yellow,
+    /// Orange.
+    Orange,
+    /// Red.
+    Red,
+    /// Purple.
+    Purple,
+    /// Magenta.
+    Magenta,
+    /// Green.
+    Green,
+    /// Teal.
+    Teal,
+    /// Blue.
+    Blue,
+}
+
+/// How strictly photos are filtered for safe content
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFilter {
+    /// Filter out unsafe content.
+    /// Default if unspecified.
+    Low,
+    /// Filter out unsafe content and anything which may be unsafe.
+    High,
+}
+
 impl Photo {
     /// Gets the download URL for this photo.
     ///
@@ -131,20 +290,104 @@ impl Photo {
     /// returned by the photo's download endpoint (/photo/<id>/download).
     /// The URL of the download endpoint is
     /// accessable from a Photo object (photo.links.download_location).
+    ///
+    /// # Errors
+    /// - Request is raised if the download location is not a valid URI.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
     pub fn get_download_url<C>(
         &self,
-        client: &Client<C>,
-        access_key: &str,
+        client: &Unsplash<C>,
     ) -> impl Future<Item = Url, Error = Error>
     where
         C: Connect + 'static,
     {
-        ::endpoint::get(
-            (),
-            &client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            self.links.download_location.parse().unwrap(),
-        )
+        ::endpoint::get(&(), client, &client.auth(), self.links.download_location.clone())
+    }
+
+    /// Downloads this photo, yielding its data as a Stream of Chunks.
+    ///
+    /// The download is tracked through the photo's download endpoint, as
+    /// Unsplash requires, before the photo is requested from the URL it
+    /// returns.
+    ///
+    /// # Errors
+    /// - Request is raised if the download location or returned URL is not a
+    /// valid URI.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - Forbidden is raised if access to the photo is denied.
+    /// - UnexpectedStatus is raised if the photo cannot be downloaded.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    ///     - wrapping a Hyper error is raised if there is an error reading
+    /// the photo's data.
+    pub fn download<C>(&self, client: &Unsplash<C>) -> impl Stream<Item = Chunk, Error = Error>
+    where
+        C: Connect + 'static,
+    {
+        download(client.clone(), self.links.download_location.clone())
+    }
+
+    /// Like this photo on behalf of the user the bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant write_likes.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn like<C>(
+        &self,
+        client: &Unsplash<C>,
+        bearer: &Bearer,
+    ) -> impl Future<Item = Like, Error = Error>
+    where
+        C: Connect + 'static,
+    {
+        match bearer.auth(Scope::WriteLikes) {
+            Ok(auth) => Either::A(::endpoint::post(
+                &(),
+                client,
+                &auth,
+                client.url(&format!("photos/{}/like", self.id)),
+            )),
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+
+    /// Remove the like of the user the bearer token belongs to from this
+    /// photo.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant write_likes.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn unlike<C>(
+        &self,
+        client: &Unsplash<C>,
+        bearer: &Bearer,
+    ) -> impl Future<Item = Like, Error = Error>
+    where
+        C: Connect + 'static,
+    {
+        match bearer.auth(Scope::WriteLikes) {
+            Ok(auth) => Either::A(::endpoint::delete(
+                &(),
+                client,
+                &auth,
+                client.url(&format!("photos/{}/like", self.id)),
+            )),
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
     }
 }
 
@@ -160,10 +403,273 @@ impl Default for Order {
     fn default() -> Self { Order::Latest }
 }
 
-impl Photos {
+impl Default for ContentFilter {
+    fn default() -> Self { ContentFilter::Low }
+}
+
+impl<'a, C> Photos<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Photos { client } }
+
     /// Get a list of photos from Unsplash
-    pub fn list() -> List { List::default() }
+    pub fn list(&self) -> List<'a, C> { List::new(self.client) }
 
     /// Get a random photo/some random photos from Unsplash
-    pub fn random() -> Random { Random::default() }
+    pub fn random(&self) -> Random<'a, C> { Random::new(self.client) }
+
+    /// Get the photo with the given ID from Unsplash.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(&self, id: &str) -> impl Future<Item = Photo, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(&format!("photos/{}", id)))
+    }
+
+    /// Get the statistics of the photo with the given ID from Unsplash.
+    pub fn statistics(&self, id: &str) -> Statistics<'a, C> {
+        Statistics::new(self.client, id.to_owned())
+    }
+
+    /// Update the photo with the given ID on behalf of the user the bearer
+    /// token belongs to.
+    pub fn update(&self, id: &str, bearer: &'a Bearer) -> PhotoUpdate<'a, C> {
+        PhotoUpdate::new(self.client, bearer, id.to_owned())
+    }
+}
+
+/// Track the download through the download location, before streaming the
+/// photo from the URL it returns.
+fn download<C>(client: Unsplash<C>, location: String) -> impl Stream<Item = Chunk, Error = Error>
+where
+    C: Connect + 'static,
+{
+    ::endpoint::get(&(), &client, &client.auth(), location)
+        .and_then(move |url: Url| ::endpoint::get_raw(&client, url.as_ref(), 0, None))
+        .map(|res| {
+            res.into_body().map_err(|e| Error::from(e.context(ErrorKind::MalformedResponse)))
+        })
+        .flatten_stream()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A photo as returned from getting a single photo.
+    const PHOTO: &str = r##"{
+        "id": "Dwu85P9SOIk",
+        "created_at": "2016-05-03T11:00:28-04:00",
+        "updated_at": "2016-07-10T11:00:01-05:00",
+        "width": 2448,
+        "height": 3264,
+        "color": "#6E633A",
+        "blur_hash": "LFC$yHwc8^$yIAS$%M%00KxukYIp",
+        "downloads": 1345,
+        "views": 2354,
+        "likes": 24,
+        "liked_by_user": false,
+        "description": "A man drinking a coffee.",
+        "exif": {
+            "make": "Canon",
+            "model": "Canon EOS 40D",
+            "name": "Canon, EOS 40D",
+            "exposure_time": "0.011111111111111112",
+            "aperture": "4.970854",
+            "focal_length": "37",
+            "iso": 100
+        },
+        "location": {
+            "name": "Montreal, Canada",
+            "city": "Montreal",
+            "country": "Canada",
+            "position": { "latitude": 45.473298, "longitude": -73.638488 }
+        },
+        "tags": [{ "title": "man" }, { "title": "drinking" }, { "title": "coffee" }],
+        "related_collections": {
+            "total": 1,
+            "type": "related",
+            "results": [{
+                "id": "3330452",
+                "title": "Coffee",
+                "description": null,
+                "published_at": "2018-09-11T15:36:10-04:00",
+                "last_collected_at": "2021-05-06T11:27:31-04:00",
+                "updated_at": "2021-05-06T11:27:31-04:00",
+                "curated": false,
+                "featured": false,
+                "total_photos": 41,
+                "private": false,
+                "share_key": "0b6b4fa7ab5e2b0d46b3f0a1c8a7e4c3",
+                "links": {
+                    "self": "https://api.unsplash.com/collections/3330452",
+                    "html": "https://unsplash.com/collections/3330452/coffee",
+                    "photos": "https://api.unsplash.com/collections/3330452/photos",
+                    "related": "https://api.unsplash.com/collections/3330452/related"
+                }
+            }]
+        },
+        "current_user_collections": [],
+        "urls": {
+            "raw": "https://images.unsplash.com/face-springmorning.jpg",
+            "full": "https://images.unsplash.com/face-springmorning.jpg?q=75&fm=jpg",
+            "regular": "https://images.unsplash.com/face-springmorning.jpg?q=75&fm=jpg&w=1080&fit=max",
+            "small": "https://images.unsplash.com/face-springmorning.jpg?q=75&fm=jpg&w=400&fit=max",
+            "thumb": "https://images.unsplash.com/face-springmorning.jpg?q=75&fm=jpg&w=200&fit=max"
+        },
+        "links": {
+            "self": "https://api.unsplash.com/photos/Dwu85P9SOIk",
+            "html": "https://unsplash.com/photos/Dwu85P9SOIk",
+            "download": "https://unsplash.com/photos/Dwu85P9SOIk/download",
+            "download_location": "https://api.unsplash.com/photos/Dwu85P9SOIk/download"
+        },
+        "user": {
+            "id": "QPxL2MGqfrw",
+            "updated_at": "2016-07-10T11:00:01-05:00",
+            "username": "exampleuser",
+            "name": "Joe Example",
+            "portfolio_url": "https://example.com/",
+            "bio": "Just an everyday Joe",
+            "location": "Montreal",
+            "total_likes": 5,
+            "total_photos": 10,
+            "total_collections": 13,
+            "instagram_username": null,
+            "twitter_username": null,
+            "profile_image": {
+                "small": "https://images.unsplash.com/profile-1.jpg?w=32",
+                "medium": "https://images.unsplash.com/profile-1.jpg?w=64",
+                "large": "https://images.unsplash.com/profile-1.jpg?w=128"
+            },
+            "links": {
+                "self": "https://api.unsplash.com/users/exampleuser",
+                "html": "https://unsplash.com/exampleuser",
+                "photos": "https://api.unsplash.com/users/exampleuser/photos",
+                "likes": "https://api.unsplash.com/users/exampleuser/likes",
+                "portfolio": "https://api.unsplash.com/users/exampleuser/portfolio"
+            }
+        }
+    }"##;
+
+    /// A photo and user as returned from liking a photo.
+    const LIKE: &str = r##"{
+        "photo": {
+            "id": "LF8gK8-HGSg",
+            "width": 5245,
+            "height": 3497,
+            "color": "#60544D",
+            "blur_hash": "LFC$yHwc8^$yIAS$%M%00KxukYIp",
+            "likes": 10,
+            "liked_by_user": true,
+            "description": "A man drinking a coffee.",
+            "urls": {
+                "raw": "https://images.unsplash.com/1464120435851-63f6f1d4a1f8",
+                "full": "https://images.unsplash.com/1464120435851-63f6f1d4a1f8?q=75&fm=jpg",
+                "regular": "https://images.unsplash.com/1464120435851-63f6f1d4a1f8?q=75&w=1080",
+                "small": "https://images.unsplash.com/1464120435851-63f6f1d4a1f8?q=75&w=400",
+                "thumb": "https://images.unsplash.com/1464120435851-63f6f1d4a1f8?q=75&w=200"
+            },
+            "links": {
+                "self": "https://api.unsplash.com/photos/LF8gK8-HGSg",
+                "html": "http://unsplash.com/photos/LF8gK8-HGSg",
+                "download": "http://unsplash.com/photos/LF8gK8-HGSg/download"
+            }
+        },
+        "user": {
+            "id": "8VpB0GYJMZQ",
+            "username": "williamnot",
+            "name": "Thomas R.",
+            "links": {
+                "self": "https://api.unsplash.com/users/williamnot",
+                "html": "http://api.unsplash.com/williamnot",
+                "photos": "http://api.unsplash.com/williamnot/photos",
+                "likes": "http://api.unsplash.com/williamnot/likes"
+            }
+        }
+    }"##;
+
+    #[test]
+    fn like() {
+        let like: Like = ::serde_json::from_str(LIKE).unwrap();
+        assert_eq!(like.photo.id, "LF8gK8-HGSg");
+        assert_eq!(like.photo.likes, 10);
+        assert!(like.photo.liked_by_user);
+        assert_eq!(like.user.username, "williamnot");
+    }
+
+    #[test]
+    fn photo() {
+        let photo: Photo = ::serde_json::from_str(PHOTO).unwrap();
+        assert_eq!(photo.id, "Dwu85P9SOIk");
+        assert_eq!(photo.downloads, Some(1345));
+        assert_eq!(photo.exif.unwrap().iso, Some(100));
+        assert_eq!(photo.location.unwrap().position.unwrap().latitude, Some(45.473298));
+        assert_eq!(photo.tags.unwrap().len(), 3);
+
+        let related = photo.related_collections.unwrap();
+        assert_eq!(related.total, 1);
+        assert_eq!(related.results[0].id, "3330452");
+        assert_eq!(related.results[0].total_photos, Some(41));
+    }
+
+    /// Statistics of a photo as returned from listing a user's photos with
+    /// their statistics.
+    const STATISTICS: &str = r#"{
+        "downloads": {
+            "total": 1345,
+            "historical": {
+                "change": 3,
+                "resolution": "days",
+                "quantity": 2,
+                "values": [
+                    { "date": "2016-07-09", "value": 1 },
+                    { "date": "2016-07-10", "value": 2 }
+                ]
+            }
+        },
+        "views": {
+            "total": 2354,
+            "historical": {
+                "change": 0,
+                "resolution": "days",
+                "quantity": 2,
+                "values": [
+                    { "date": "2016-07-09", "value": 0 },
+                    { "date": "2016-07-10", "value": 0 }
+                ]
+            }
+        },
+        "likes": {
+            "total": 24,
+            "historical": {
+                "change": -1,
+                "resolution": "days",
+                "quantity": 2,
+                "values": [
+                    { "date": "2016-07-09", "value": 0 },
+                    { "date": "2016-07-10", "value": -1 }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn photo_with_statistics() {
+        let mut photo: ::serde_json::Value = ::serde_json::from_str(PHOTO).unwrap();
+        photo["statistics"] = ::serde_json::from_str(STATISTICS).unwrap();
+        let photo: Photo = ::serde_json::from_value(photo).unwrap();
+
+        let statistics = photo.statistics.unwrap();
+        assert_eq!(statistics.id, None);
+        assert_eq!(statistics.downloads.total, 1345);
+        assert_eq!(statistics.downloads.historical.change, 3);
+        assert_eq!(statistics.likes.historical.values[1].value, -1);
+        assert_eq!(statistics.views.historical.resolution, Resolution::Days);
+    }
 }
@@ -1,59 +1,60 @@
+use client::Unsplash;
 use futures::Future;
-use hyper::{client::connect::Connect, Client, Uri};
+use hyper::client::connect::Connect;
 use itertools::*;
 
-use super::{Orientation, Photo};
+use super::{ContentFilter, Orientation, Photo};
 use error::*;
 
-lazy_static! {
-    /// URI of the endpoint to get random photos from Unsplash.
-    pub static ref RANDOM_URI: Uri = format!("{}{}", ::API_URL, "photos/random").parse().unwrap();
-}
+/// Path of the endpoint to get random photos from Unsplash.
+pub const RANDOM_PATH: &str = "photos/random";
 
 /// Request builder for creating a Random request.
-#[derive(Debug, Default)]
-pub struct Random {
+#[derive(Debug)]
+pub struct Random<'a, C: 'a> {
+    client: &'a Unsplash<C>,
     featured: Option<bool>,
     username: Option<String>,
     w: Option<usize>,
     h: Option<usize>,
     orientation: Option<Orientation>,
+    content_filter: Option<ContentFilter>,
 }
 
 /// Session type to handle returning a list of random photos.
-#[derive(Debug, Default)]
-pub struct RandomCount {
-    rand: Random,
+#[derive(Debug)]
+pub struct RandomCount<'a, C: 'a> {
+    rand: Random<'a, C>,
     count: usize,
 }
 
 /// Session type to handle restricting the photos to a query.
-#[derive(Debug, Default)]
-pub struct RandomQuery {
-    rand: Random,
+#[derive(Debug)]
+pub struct RandomQuery<'a, C: 'a> {
+    rand: Random<'a, C>,
     query: String,
 }
 
 /// Session type to handle returning a list of random photos restricted by a
 /// query.
-#[derive(Debug, Default)]
-pub struct RandomQueryCount {
-    rand: RandomQuery,
+#[derive(Debug)]
+pub struct RandomQueryCount<'a, C: 'a> {
+    rand: RandomQuery<'a, C>,
     count: usize,
 }
 
 /// Session type to handle restricting the photos to a set of collections.
-#[derive(Debug, Default)]
-pub struct RandomCollection {
-    rand: Random,
+#[derive(Debug)]
+pub struct RandomCollection<'a, C: 'a> {
+    rand: Random<'a, C>,
     collection: String,
 }
 
 /// Session type to handle returning a list of random photos restricted by a
 /// set of collections.
-#[derive(Debug, Default)]
-pub struct RandomCollectionCount {
-    rand: RandomCollection,
+#[derive(Debug)]
+pub struct RandomCollectionCount<'a, C: 'a> {
+    rand: RandomCollection<'a, C>,
     count: usize,
 }
 
@@ -65,6 +66,7 @@ struct RandomSerialize {
     w: Option<usize>,
     h: Option<usize>,
     orientation: Option<Orientation>,
+    content_filter: Option<ContentFilter>,
     collection: Option<String>,
     query: Option<String>,
 }
@@ -77,12 +79,28 @@ struct RandomCountSerialize {
     w: Option<usize>,
     h: Option<usize>,
     orientation: Option<Orientation>,
+    content_filter: Option<ContentFilter>,
     collection: Option<String>,
     query: Option<String>,
     count: usize,
 }
 
-impl Random {
+impl<'a, C> Random<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self {
+        Random {
+            client,
+            featured: None,
+            username: None,
+            w: None,
+            h: None,
+            orientation: None,
+            content_filter: None,
+        }
+    }
+
     /// Restrict the photos to only featured photos.
     pub fn featured(mut self, feat: bool) -> Self {
         self.featured.replace(feat);
@@ -113,14 +131,21 @@ impl Random {
         self
     }
 
+    /// Restrict the photos to only photos which are safe for the given content
+    /// filter.
+    pub fn content_filter(mut self, content_filter: ContentFilter) -> Self {
+        self.content_filter.replace(content_filter);
+        self
+    }
+
     /// Restrict the photos to only photos which match the given query. NOTE:
     /// only the number of photos can be set after this is called.
-    pub fn query(self, query: String) -> RandomQuery { RandomQuery { rand: self, query } }
+    pub fn query(self, query: String) -> RandomQuery<'a, C> { RandomQuery { rand: self, query } }
 
     /// Restrict the photos to only photos which are within the given
     /// collections. NOTE: only the number of photos can be set this is
     /// called.
-    pub fn collection<I>(self, collection: I) -> RandomCollection
+    pub fn collection<I>(self, collection: I) -> RandomCollection<'a, C>
     where
         I: IntoIterator<Item = String>,
     {
@@ -129,7 +154,7 @@ impl Random {
 
     /// Specify the the number of photos to get. NOTE: nothing can be set after
     /// this is called.
-    pub fn count(self, count: usize) -> RandomCount {
+    pub fn count(self, count: usize) -> RandomCount<'a, C> {
         assert_ne!(count, 0, "Cannot get 0 images!");
         RandomCount { rand: self, count }
     }
@@ -142,31 +167,29 @@ impl Random {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Photo, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Photo, Error = Error> {
+        let client = self.client;
         let serial = RandomSerialize {
             featured: self.featured,
             username: self.username,
             w: self.w,
             h: self.h,
             orientation: self.orientation,
+            content_filter: self.content_filter,
             collection: None,
             query: None,
         };
-        ::endpoint::get(serial, client, access_key, RANDOM_URI.clone())
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
     }
 }
 
-impl RandomQuery {
+impl<'a, C> RandomQuery<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Specify the the number of photos to get. NOTE: nothing can be set after
     /// this is called.
-    pub fn count(self, count: usize) -> RandomQueryCount {
+    pub fn count(self, count: usize) -> RandomQueryCount<'a, C> {
         assert_ne!(count, 0, "Cannot get 0 images!");
         RandomQueryCount { rand: self, count }
     }
@@ -179,36 +202,29 @@ impl RandomQuery {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Photo, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Photo, Error = Error> {
+        let client = self.rand.client;
         let serial = RandomSerialize {
             featured: self.rand.featured,
             username: self.rand.username,
             w: self.rand.w,
             h: self.rand.h,
             orientation: self.rand.orientation,
+            content_filter: self.rand.content_filter,
             collection: None,
             query: Some(self.query),
         };
-        ::endpoint::get(
-            serial,
-            &client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            RANDOM_URI.clone(),
-        )
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
     }
 }
 
-impl RandomCollection {
+impl<'a, C> RandomCollection<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Specify the the number of photos to get. NOTE: nothing can be set after
     /// this is called.
-    pub fn count(self, count: usize) -> RandomCollectionCount {
+    pub fn count(self, count: usize) -> RandomCollectionCount<'a, C> {
         assert_ne!(count, 0, "Cannot get 0 images!");
         RandomCollectionCount { rand: self, count }
     }
@@ -221,33 +237,26 @@ impl RandomCollection {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Photo, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Photo, Error = Error> {
+        let client = self.rand.client;
         let serial = RandomSerialize {
             featured: self.rand.featured,
             username: self.rand.username,
             w: self.rand.w,
             h: self.rand.h,
             orientation: self.rand.orientation,
+            content_filter: self.rand.content_filter,
             collection: Some(self.collection),
             query: None,
         };
-        ::endpoint::get(
-            serial,
-            client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            RANDOM_URI.clone(),
-        )
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
     }
 }
 
-impl RandomCount {
+impl<'a, C> RandomCount<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Get the random photos.
     ///
     /// # Errors
@@ -256,34 +265,27 @@ impl RandomCount {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Vec<Photo>, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Vec<Photo>, Error = Error> {
+        let client = self.rand.client;
         let serial = RandomCountSerialize {
             featured: self.rand.featured,
             username: self.rand.username,
             w: self.rand.w,
             h: self.rand.h,
             orientation: self.rand.orientation,
+            content_filter: self.rand.content_filter,
             collection: None,
             query: None,
             count: self.count,
         };
-        ::endpoint::get(
-            serial,
-            client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            RANDOM_URI.clone(),
-        )
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
     }
 }
 
-impl RandomQueryCount {
+impl<'a, C> RandomQueryCount<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Get the random photos which matches the query.
     ///
     /// # Errors
@@ -292,34 +294,27 @@ impl RandomQueryCount {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Vec<Photo>, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Vec<Photo>, Error = Error> {
+        let client = self.rand.rand.client;
         let serial = RandomCountSerialize {
             featured: self.rand.rand.featured,
             username: self.rand.rand.username,
             w: self.rand.rand.w,
             h: self.rand.rand.h,
             orientation: self.rand.rand.orientation,
+            content_filter: self.rand.rand.content_filter,
             collection: None,
             query: Some(self.rand.query),
             count: self.count,
         };
-        ::endpoint::get(
-            serial,
-            client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            RANDOM_URI.clone(),
-        )
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
     }
 }
 
-impl RandomCollectionCount {
+impl<'a, C> RandomCollectionCount<'a, C>
+where
+    C: Connect + 'static,
+{
     /// Get the random photos which are in the collections.
     ///
     /// # Errors
@@ -328,29 +323,35 @@ impl RandomCollectionCount {
     /// - MalformedResponse
     ///     - wrapping a JSON error is raised if the JSON returned from
     /// Unsplash is invalid.
-    pub fn get<C>(
-        self,
-        client: &Client<C>,
-        access_key: &str,
-    ) -> impl Future<Item = Vec<Photo>, Error = Error>
-    where
-        C: Connect + 'static,
-    {
+    pub fn get(self) -> impl Future<Item = Vec<Photo>, Error = Error> {
+        let client = self.rand.rand.client;
         let serial = RandomCountSerialize {
             featured: self.rand.rand.featured,
             username: self.rand.rand.username,
             w: self.rand.rand.w,
             h: self.rand.rand.h,
             orientation: self.rand.rand.orientation,
+            content_filter: self.rand.rand.content_filter,
             collection: Some(self.rand.collection),
             query: None,
             count: self.count,
         };
-        ::endpoint::get(
-            serial,
-            client,
-            format!("Client-ID: {}", access_key).as_ref(),
-            RANDOM_URI.clone(),
-        )
+        ::endpoint::get(&serial, client, &client.auth(), client.url(RANDOM_PATH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use endpoint::ToQuery;
+
+    #[test]
+    fn query() {
+        let serial = RandomSerialize {
+            content_filter: Some(ContentFilter::High),
+            query: Some("office".to_owned()),
+            ..RandomSerialize::default()
+        };
+        assert_eq!(serial.to_query(), "?content_filter=high&query=office");
     }
 }
@@ -0,0 +1,346 @@
+use client::Unsplash;
+use failure::Fail;
+use futures::{future::Either, Future, Stream};
+use hyper::{client::connect::Connect,
+            header::{CONTENT_LENGTH, CONTENT_RANGE, ETAG, LAST_MODIFIED},
+            Body, Response, StatusCode};
+
+use std::{fs::{self, File, OpenOptions},
+          io::{self, Write},
+          path::{Path, PathBuf}};
+
+use super::{Photo, Url};
+use error::*;
+
+/// Progress of saving a photo to disk.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Progress {
+    /// Number of bytes saved, including any saved before resuming.
+    pub saved: u64,
+    /// Total size of the photo in bytes, if Unsplash reported it.
+    pub total: Option<u64>,
+}
+
+impl Photo {
+    /// Saves this photo to the given path, calling progress after each chunk
+    /// of the photo is written.
+    ///
+    /// The photo is downloaded as by [download](#method.download) into a
+    /// temporary file next to the path, named with a '.part' extension, which
+    /// is renamed to the path once complete. If the temporary file already
+    /// exists, i.e. from an interrupted save, the download resumes from the end
+    /// of it.
+    ///
+    /// A download only resumes if Unsplash confirms the photo is unchanged,
+    /// using the ETag or Last-Modified date of the photo, which is stored next
+    /// to the temporary file with a '.part.validator' extension. Otherwise, the
+    /// photo is downloaded from scratch.
+    ///
+    /// Writing to disk blocks the thread polling the returned Future.
+    ///
+    /// # Errors
+    /// - Io wrapping an IO error is raised if the photo cannot be written to
+    /// disk.
+    /// - Request is raised if the download location or returned URL is not a
+    /// valid URI.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - Forbidden is raised if access to the photo is denied.
+    /// - UnexpectedStatus is raised if the photo cannot be downloaded.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    ///     - wrapping a Hyper error is raised if there is an error reading
+    /// the photo's data.
+    pub fn save<C, P, F>(
+        &self,
+        client: &Unsplash<C>,
+        path: P,
+        progress: F,
+    ) -> impl Future<Item = (), Error = Error>
+    where
+        C: Connect + 'static,
+        P: AsRef<Path>,
+        F: FnMut(Progress),
+    {
+        let path = path.as_ref().to_path_buf();
+        let mut part = path.clone().into_os_string();
+        part.push(".part");
+        let mut validator = part.clone();
+        validator.push(".validator");
+        save(
+            client.clone(),
+            self.links.download_location.clone(),
+            Paths { path, part: part.into(), validator: validator.into() },
+            progress,
+        )
+    }
+}
+
+/// Paths used while saving a photo.
+#[derive(Debug, Clone)]
+struct Paths {
+    /// Where the photo is saved once complete.
+    path: PathBuf,
+    /// Where the photo is saved while incomplete.
+    part: PathBuf,
+    /// Where the validator of the incomplete photo is stored.
+    validator: PathBuf,
+}
+
+/// How to continue from the response to a request to resume a download.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Resume {
+    /// The part already holds the whole photo.
+    Complete,
+    /// The response holds the rest of the photo, or the whole photo.
+    Continue,
+    /// The response doesn't continue the part, so the photo must be requested
+    /// from scratch.
+    Restart,
+}
+
+/// Track the download through the download location, before saving the photo
+/// from the URL it returns into the part, then renaming the part to the path.
+fn save<C, F>(
+    client: Unsplash<C>,
+    location: String,
+    paths: Paths,
+    mut progress: F,
+) -> impl Future<Item = (), Error = Error>
+where
+    C: Connect + 'static,
+    F: FnMut(Progress),
+{
+    let (resume_paths, rename_paths) = (paths.clone(), paths.clone());
+
+    ::futures::future::lazy(move || {
+        // Only resume from a part which can be checked to be the same photo.
+        let validator = fs::read_to_string(&resume_paths.validator).ok();
+        let offset = match validator {
+            Some(_) => fs::metadata(&resume_paths.part).map(|metadata| metadata.len()).unwrap_or(0),
+            None => 0,
+        };
+        debug!("resuming {} from {}", resume_paths.part.display(), offset);
+
+        ::endpoint::get(&(), &client, &client.auth(), location)
+            .and_then(move |url: Url| fetch(client, url.as_ref().to_owned(), offset, validator))
+            .map(move |res| (res, offset))
+    })
+    .and_then(move |(res, offset)| {
+        let res = match res {
+            Some(res) => res,
+            None => {
+                progress(Progress { saved: offset, total: Some(offset) });
+                return Either::B(::futures::future::ok(()));
+            },
+        };
+        let start = if res.status() == StatusCode::PARTIAL_CONTENT { offset } else { 0 };
+        let total = content_length(&res).map(|len| start + len);
+        let file = store_validator(&res, start, &paths.validator).and_then(|_| {
+            OpenOptions::new()
+                .create(true)
+                .append(start != 0)
+                .write(true)
+                .truncate(start == 0)
+                .open(&paths.part)
+                .map_err(|e| Error::from(e.context(ErrorKind::Io)))
+        });
+        let file = match file {
+            Ok(file) => file,
+            Err(e) => return Either::B(::futures::future::err(e)),
+        };
+
+        Either::A(
+            res.into_body()
+                .map_err(|e| Error::from(e.context(ErrorKind::MalformedResponse)))
+                .fold((file, start), move |(file, saved), chunk| {
+                    let saved = saved + chunk.len() as u64;
+                    write(file, &chunk).map(|file| {
+                        progress(Progress { saved, total });
+                        (file, saved)
+                    })
+                })
+                .and_then(|(file, _)| {
+                    file.sync_all().map_err(|e| Error::from(e.context(ErrorKind::Io)))
+                }),
+        )
+    })
+    .and_then(move |_| {
+        fs::rename(&rename_paths.part, &rename_paths.path)
+            .and_then(|_| remove(&rename_paths.validator))
+            .map_err(|e| Error::from(e.context(ErrorKind::Io)))
+    })
+}
+
+/// Request the photo from offset, as long as it still matches the validator,
+/// requesting the whole photo again if the response doesn't continue the
+/// part. Returns None if the part already holds the whole photo.
+fn fetch<C>(
+    client: Unsplash<C>,
+    url: String,
+    offset: u64,
+    validator: Option<String>,
+) -> impl Future<Item = Option<Response<Body>>, Error = Error>
+where
+    C: Connect + 'static,
+{
+    let if_range = validator.as_ref().map(String::as_str);
+    ::endpoint::get_raw(&client, &url, offset, if_range).and_then(move |res| {
+        match resume(&res, offset) {
+            Resume::Complete => Either::A(::futures::future::ok(None)),
+            Resume::Continue => Either::A(::futures::future::ok(Some(res))),
+            Resume::Restart => {
+                debug!("cannot resume from {}, restarting", offset);
+                Either::B(::endpoint::get_raw(&client, &url, 0, None).map(Some))
+            },
+        }
+    })
+}
+
+/// Check whether the response continues a part of offset bytes.
+fn resume(res: &Response<Body>, offset: u64) -> Resume {
+    let range = res.headers().get(CONTENT_RANGE).and_then(|range| range.to_str().ok());
+    match res.status() {
+        // Nothing left to request, as long as the part is as long as the photo.
+        StatusCode::RANGE_NOT_SATISFIABLE => {
+            if range.and_then(range_total) == Some(offset) {
+                Resume::Complete
+            } else {
+                Resume::Restart
+            }
+        },
+        StatusCode::PARTIAL_CONTENT => {
+            if range.and_then(range_start) == Some(offset) {
+                Resume::Continue
+            } else {
+                Resume::Restart
+            }
+        },
+        // Unsplash may ignore the range, or the photo has changed, in which
+        // case it starts from scratch.
+        _ => Resume::Continue,
+    }
+}
+
+/// Start of a Content-Range, i.e. 7 in `bytes 7-19/20`.
+fn range_start(range: &str) -> Option<u64> {
+    if !range.starts_with("bytes ") {
+        return None;
+    }
+    range[6..].splitn(2, '-').next()?.trim().parse().ok()
+}
+
+/// Total length of a Content-Range, i.e. 20 in `bytes 7-19/20` or `bytes */20`.
+fn range_total(range: &str) -> Option<u64> {
+    if !range.starts_with("bytes ") {
+        return None;
+    }
+    range[6..].rsplitn(2, '/').next()?.trim().parse().ok()
+}
+
+/// Store the validator of the photo when saving it from scratch, so the save
+/// can only be resumed if the photo is unchanged.
+///
+/// Weak ETags cannot be used to resume, so the Last-Modified date is stored
+/// instead.
+fn store_validator(res: &Response<Body>, start: u64, validator: &Path) -> Result<(), Error> {
+    if start != 0 {
+        return Ok(());
+    }
+    let value = res
+        .headers()
+        .get(ETAG)
+        .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+        .or_else(|| res.headers().get(LAST_MODIFIED))
+        .and_then(|value| value.to_str().ok());
+    match value {
+        Some(value) => fs::write(validator, value),
+        None => remove(validator),
+    }
+    .map_err(|e| Error::from(e.context(ErrorKind::Io)))
+}
+
+/// Remove the file, if it exists.
+fn remove(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        res => res,
+    }
+}
+
+/// Write a chunk to the file, returning the file to write the next chunk.
+fn write(mut file: File, chunk: &[u8]) -> Result<File, Error> {
+    file.write_all(chunk).map_err(|e| Error::from(e.context(ErrorKind::Io)))?;
+    Ok(file)
+}
+
+/// Length of the response's body, if given.
+fn content_length(res: &Response<Body>) -> Option<u64> {
+    res.headers().get(CONTENT_LENGTH)?.to_str().ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+
+    use std::{env, process};
+
+    fn response(status: StatusCode, range: Option<&str>) -> Response<Body> {
+        let mut res = Response::new(Body::empty());
+        *res.status_mut() = status;
+        if let Some(range) = range {
+            res.headers_mut().insert(CONTENT_RANGE, HeaderValue::from_str(range).unwrap());
+        }
+        res
+    }
+
+    #[test]
+    fn resume_partial_content() {
+        let res = response(StatusCode::PARTIAL_CONTENT, Some("bytes 7-19/20"));
+        assert_eq!(resume(&res, 7), Resume::Continue);
+        assert_eq!(resume(&res, 5), Resume::Restart);
+    }
+
+    #[test]
+    fn resume_range_not_satisfiable() {
+        let res = response(StatusCode::RANGE_NOT_SATISFIABLE, Some("bytes */20"));
+        assert_eq!(resume(&res, 20), Resume::Complete);
+        assert_eq!(resume(&res, 25), Resume::Restart);
+        assert_eq!(resume(&response(StatusCode::RANGE_NOT_SATISFIABLE, None), 20), Resume::Restart);
+    }
+
+    #[test]
+    fn resume_ignored_range() {
+        assert_eq!(resume(&response(StatusCode::OK, None), 7), Resume::Continue);
+    }
+
+    #[test]
+    fn content_range() {
+        assert_eq!(range_start("bytes 7-19/20"), Some(7));
+        assert_eq!(range_start("bytes */20"), None);
+        assert_eq!(range_start("items 7-19/20"), None);
+        assert_eq!(range_total("bytes 7-19/20"), Some(20));
+        assert_eq!(range_total("bytes */20"), Some(20));
+        assert_eq!(range_total("bytes 7-19/*"), None);
+    }
+
+    #[test]
+    fn weak_etag_validator() {
+        let path = env::temp_dir().join(format!("unsplash-validator-{}", process::id()));
+        let mut res = response(StatusCode::OK, None);
+        res.headers_mut().insert(ETAG, HeaderValue::from_static("W/\"abc\""));
+        res.headers_mut()
+            .insert(LAST_MODIFIED, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+
+        store_validator(&res, 0, &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Wed, 21 Oct 2015 07:28:00 GMT");
+
+        res.headers_mut().insert(ETAG, HeaderValue::from_static("\"abc\""));
+        store_validator(&res, 0, &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "\"abc\"");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::NaiveDate;
+use client::Unsplash;
+use futures::Future;
+use hyper::client::connect::Connect;
+
+use error::*;
+
+/// Request builder for creating a Statistics request.
+#[derive(Debug, Serialize, Clone)]
+pub struct Statistics<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    id: String,
+    resolution: Option<Resolution>,
+    quantity: Option<usize>,
+}
+
+/// Statistics of a photo.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PhotoStatistics {
+    /// Photo ID (only when getting the statistics of a photo, rather than
+    /// listing photos with their statistics).
+    pub id: Option<String>,
+    /// Downloads of the photo.
+    pub downloads: Statistic,
+    /// Views of the photo.
+    pub views: Statistic,
+    /// Likes of the photo.
+    pub likes: Statistic,
+}
+
+/// A statistic, with its total and its history.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Statistic {
+    /// Total over the lifetime of the photo.
+    pub total: usize,
+    /// History over the requested period.
+    pub historical: Historical,
+}
+
+/// History of a statistic.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Historical {
+    /// Change over the period.
+    pub change: i64,
+    /// Resolution of each data point.
+    pub resolution: Resolution,
+    /// Number of data points.
+    pub quantity: usize,
+    /// The data points, oldest first.
+    pub values: Vec<DataPoint>,
+}
+
+/// Value of a statistic on a single day.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct DataPoint {
+    /// Day of the data point.
+    pub date: NaiveDate,
+    /// Value on that day.
+    pub value: i64,
+}
+
+/// Resolution of the history of a statistic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    /// A data point per day.
+    /// Default if unspecified.
+    Days,
+}
+
+impl<'a, C> Statistics<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, id: String) -> Self {
+        Statistics { client, id, resolution: None, quantity: None }
+    }
+
+    /// Specify the resolution of the history.
+    pub fn resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution.replace(resolution);
+        self
+    }
+
+    /// Specify how many data points of history to get.
+    ///
+    /// # Panics
+    /// Panics if quantity is not between 1 and 30.
+    pub fn quantity(mut self, quantity: usize) -> Self {
+        assert!(quantity >= 1 && quantity <= 30, "Quantity must be between 1 and 30!");
+        self.quantity.replace(quantity);
+        self
+    }
+
+    /// Get the statistics of the photo.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = PhotoStatistics, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("photos/{}/statistics", self.id));
+        ::endpoint::get(&self, client, &client.auth(), uri)
+    }
+}
+
+impl Default for Resolution {
+    fn default() -> Self { Resolution::Days }
+}
@@ -0,0 +1,182 @@
+use auth::Bearer;
+use client::Unsplash;
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
+use oauth::Scope;
+
+use super::Photo;
+use error::*;
+
+/// Session type for handling photo updates
+#[derive(Debug, Serialize)]
+pub struct PhotoUpdate<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    bearer: &'a Bearer,
+    #[serde(skip)]
+    id: String,
+    description: Option<String>,
+    #[serde(rename = "location[latitude]")]
+    latitude: Option<f64>,
+    #[serde(rename = "location[longitude]")]
+    longitude: Option<f64>,
+    #[serde(rename = "location[name]")]
+    name: Option<String>,
+    #[serde(rename = "location[city]")]
+    city: Option<String>,
+    #[serde(rename = "location[country]")]
+    country: Option<String>,
+    #[serde(rename = "exif[make]")]
+    make: Option<String>,
+    #[serde(rename = "exif[model]")]
+    model: Option<String>,
+    #[serde(rename = "exif[exposure_time]")]
+    exposure_time: Option<String>,
+    #[serde(rename = "exif[aperture_value]")]
+    aperture: Option<String>,
+    #[serde(rename = "exif[focal_length]")]
+    focal_length: Option<String>,
+    #[serde(rename = "exif[iso_speed_ratings]")]
+    iso: Option<usize>,
+}
+
+impl<'a, C> PhotoUpdate<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, bearer: &'a Bearer, id: String) -> Self {
+        PhotoUpdate {
+            client,
+            bearer,
+            id,
+            description: None,
+            latitude: None,
+            longitude: None,
+            name: None,
+            city: None,
+            country: None,
+            make: None,
+            model: None,
+            exposure_time: None,
+            aperture: None,
+            focal_length: None,
+            iso: None,
+        }
+    }
+
+    /// Update the photo's description.
+    pub fn description(mut self, description: String) -> Self {
+        self.description.replace(description);
+        self
+    }
+
+    /// Update the latitude of where the photo was taken.
+    pub fn latitude(mut self, latitude: f64) -> Self {
+        self.latitude.replace(latitude);
+        self
+    }
+
+    /// Update the longitude of where the photo was taken.
+    pub fn longitude(mut self, longitude: f64) -> Self {
+        self.longitude.replace(longitude);
+        self
+    }
+
+    /// Update the name of where the photo was taken.
+    pub fn location_name(mut self, name: String) -> Self {
+        self.name.replace(name);
+        self
+    }
+
+    /// Update the city where the photo was taken.
+    pub fn city(mut self, city: String) -> Self {
+        self.city.replace(city);
+        self
+    }
+
+    /// Update the country where the photo was taken.
+    pub fn country(mut self, country: String) -> Self {
+        self.country.replace(country);
+        self
+    }
+
+    /// Update the make of the camera.
+    pub fn make(mut self, make: String) -> Self {
+        self.make.replace(make);
+        self
+    }
+
+    /// Update the model of the camera.
+    pub fn model(mut self, model: String) -> Self {
+        self.model.replace(model);
+        self
+    }
+
+    /// Update the exposure time, i.e. 1/100.
+    pub fn exposure_time(mut self, exposure_time: String) -> Self {
+        self.exposure_time.replace(exposure_time);
+        self
+    }
+
+    /// Update the aperture, i.e. 4.0.
+    pub fn aperture(mut self, aperture: String) -> Self {
+        self.aperture.replace(aperture);
+        self
+    }
+
+    /// Update the focal length, i.e. 50.0.
+    pub fn focal_length(mut self, focal_length: String) -> Self {
+        self.focal_length.replace(focal_length);
+        self
+    }
+
+    /// Update the ISO speed.
+    pub fn iso(mut self, iso: usize) -> Self {
+        self.iso.replace(iso);
+        self
+    }
+
+    /// Update the photo.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant write_photos.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn update(self) -> impl Future<Item = Photo, Error = Error> {
+        let client = self.client;
+        match self.bearer.auth(Scope::WritePhotos) {
+            Ok(auth) => {
+                let uri = client.url(&format!("photos/{}", self.id));
+                Either::A(::endpoint::put(&self, client, &auth, uri))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use endpoint::ToQuery;
+    use hyper::Client;
+
+    #[test]
+    fn query() {
+        let client = Unsplash::new(Client::new(), "access key".to_owned());
+        let bearer = Bearer::new("user token".to_owned());
+        let update = PhotoUpdate::new(&client, &bearer, "Dwu85P9SOIk".to_owned())
+            .latitude(45.5)
+            .city("Montreal".to_owned())
+            .aperture("1.8".to_owned())
+            .iso(100);
+        assert_eq!(
+            update.to_query(),
+            "?location[latitude]=45.5&location[city]=Montreal&exif[aperture_value]=1.8&\
+             exif[iso_speed_ratings]=100"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use client::Unsplash;
+use endpoint::collections::Collection;
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use super::SearchResults;
+use error::*;
+
+/// Path of the endpoint to search for collections.
+pub const SEARCH_COLLECTIONS_PATH: &str = "search/collections";
+
+/// Request builder for creating a collection search request.
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchCollections<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    query: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl<'a, C> SearchCollections<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, query: String) -> Self {
+        SearchCollections { client, query, page: None, per_page: None }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many collections per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Get the collections which match the query.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = SearchResults<Collection>, Error = Error> {
+        super::get(&self, self.client, SEARCH_COLLECTIONS_PATH)
+    }
+
+    /// Stream all the collections which match the query, requesting each page
+    /// as [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Collection, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, SearchResults<Collection>>(
+            &self,
+            self.client,
+            SEARCH_COLLECTIONS_PATH,
+            page,
+        )
+    }
+}
@@ -0,0 +1,277 @@
+//! Search endpoint
+//!
+//! Access to the endpoint is through the [Search](struct.Search.html) struct,
+//! which is created by [Unsplash::search](../../client/struct.Unsplash.html#method.search).
+
+use client::Unsplash;
+use endpoint::{Cursor, Links, ToQuery};
+use futures::Future;
+use hyper::{client::connect::Connect, HeaderMap, Method};
+use serde::de::DeserializeOwned;
+
+mod collections;
+mod photos;
+mod users;
+
+use error::*;
+
+pub use self::{collections::SearchCollections,
+               photos::{SearchOrder, SearchPhotos},
+               users::SearchUsers};
+
+/// Access type to Unsplash's Search endpoint.
+#[derive(Debug, Clone)]
+pub struct Search<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
+
+/// A page of results from a search.
+///
+/// Along with the results, Unsplash links to the pages around this one, which
+/// can be jumped to by passing a cursor's page to the search's page method.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchResults<T> {
+    /// Total number of results.
+    pub total: usize,
+    /// Total number of pages of results.
+    pub total_pages: usize,
+    /// The results on this page.
+    pub results: Vec<T>,
+    /// The next page, unless this is the last page.
+    pub next: Option<Cursor>,
+    /// The previous page, unless this is the first page.
+    pub prev: Option<Cursor>,
+    /// The first page, unless this is the first page.
+    pub first: Option<Cursor>,
+    /// The last page, unless this is the last page.
+    pub last: Option<Cursor>,
+}
+
+impl<T> SearchResults<T> {
+    /// Replace the links to other pages with those parsed from the headers of
+    /// the response.
+    pub(crate) fn with_links(self, headers: &HeaderMap) -> Self {
+        let links = Links::new(headers);
+        SearchResults {
+            next: links.next,
+            prev: links.prev,
+            first: links.first,
+            last: links.last,
+            ..self
+        }
+    }
+}
+
+impl<T> IntoIterator for SearchResults<T> {
+    type IntoIter = ::std::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter { self.results.into_iter() }
+}
+
+impl<'a, C> Search<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Search { client } }
+
+    /// Search for photos which match the query.
+    pub fn photos(&self, query: String) -> SearchPhotos<'a, C> {
+        SearchPhotos::new(self.client, query)
+    }
+
+    /// Search for collections which match the query.
+    pub fn collections(&self, query: String) -> SearchCollections<'a, C> {
+        SearchCollections::new(self.client, query)
+    }
+
+    /// Search for users which match the query.
+    pub fn users(&self, query: String) -> SearchUsers<'a, C> {
+        SearchUsers::new(self.client, query)
+    }
+}
+
+/// Convenience method for performing a search, returning a Future of the page
+/// of results, along with the links to other pages parsed from the response's
+/// headers.
+fn get<C, T>(
+    query: &dyn ToQuery,
+    client: &Unsplash<C>,
+    path: &str,
+) -> impl Future<Item = SearchResults<T>, Error = Error>
+where
+    C: Connect + 'static,
+    T: DeserializeOwned,
+{
+    ::endpoint::request_with_headers(query, client, &client.auth(), client.url(path), Method::GET)
+        .map(|(headers, results): (_, SearchResults<T>)| results.with_links(&headers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use endpoint::{collections::Collection, me::User, photos::Photo};
+    use hyper::header::LINK;
+
+    /// A page of photo search results, as returned for /search/photos.
+    const PHOTOS: &str = r##"{
+        "total": 133,
+        "total_pages": 7,
+        "results": [{
+            "id": "eOLpJytrbsQ",
+            "created_at": "2014-11-18T14:35:36-05:00",
+            "updated_at": "2021-05-12T05:04:31-04:00",
+            "width": 4000,
+            "height": 3000,
+            "color": "#A7A2A1",
+            "likes": 286,
+            "liked_by_user": false,
+            "description": "A man drinking a coffee.",
+            "user": {
+                "id": "Ul0QVz12Goo",
+                "username": "ugmonk",
+                "name": "Jeff Sheldon",
+                "portfolio_url": "http://ugmonk.com/",
+                "bio": null,
+                "location": null,
+                "total_likes": 29,
+                "total_photos": 51,
+                "total_collections": 3,
+                "instagram_username": "instantgrammer",
+                "twitter_username": "ugmonk",
+                "profile_image": {
+                    "small": "https://images.unsplash.com/profile-1441298803695?w=32",
+                    "medium": "https://images.unsplash.com/profile-1441298803695?w=64",
+                    "large": "https://images.unsplash.com/profile-1441298803695?w=128"
+                },
+                "links": {
+                    "self": "https://api.unsplash.com/users/ugmonk",
+                    "html": "http://unsplash.com/@ugmonk",
+                    "photos": "https://api.unsplash.com/users/ugmonk/photos",
+                    "likes": "https://api.unsplash.com/users/ugmonk/likes",
+                    "portfolio": "https://api.unsplash.com/users/ugmonk/portfolio"
+                }
+            },
+            "current_user_collections": [],
+            "urls": {
+                "raw": "https://images.unsplash.com/photo-1416339306562",
+                "full": "https://images.unsplash.com/photo-1416339306562?q=85",
+                "regular": "https://images.unsplash.com/photo-1416339306562?w=1080",
+                "small": "https://images.unsplash.com/photo-1416339306562?w=400",
+                "thumb": "https://images.unsplash.com/photo-1416339306562?w=200"
+            },
+            "links": {
+                "self": "https://api.unsplash.com/photos/eOLpJytrbsQ",
+                "html": "http://unsplash.com/photos/eOLpJytrbsQ",
+                "download": "http://unsplash.com/photos/eOLpJytrbsQ/download",
+                "download_location": "https://api.unsplash.com/photos/eOLpJytrbsQ/download"
+            }
+        }]
+    }"##;
+
+    /// A page of collection search results, as returned for
+    /// /search/collections.
+    const COLLECTIONS: &str = r#"{
+        "total": 237,
+        "total_pages": 12,
+        "results": [{
+            "id": "193913",
+            "title": "Office",
+            "description": null,
+            "published_at": "2016-04-15T21:05:55-04:00",
+            "updated_at": "2021-05-11T12:26:30-04:00",
+            "total_photos": 60,
+            "private": false,
+            "share_key": "79ae4ee8ba4d2dd9d4a0fe4b22d8a8a0",
+            "cover_photo": null,
+            "user": null,
+            "links": {
+                "self": "https://api.unsplash.com/collections/193913",
+                "html": "https://unsplash.com/collections/193913/office",
+                "photos": "https://api.unsplash.com/collections/193913/photos",
+                "related": "https://api.unsplash.com/collections/193913/related"
+            }
+        }]
+    }"#;
+
+    /// A page of user search results, as returned for /search/users.
+    const USERS: &str = r#"{
+        "total": 44,
+        "total_pages": 3,
+        "results": [{
+            "id": "Oo6gNN5bx8I",
+            "username": "nasa",
+            "name": "NASA",
+            "portfolio_url": "https://www.nasa.gov/",
+            "bio": null,
+            "location": null,
+            "total_likes": 0,
+            "total_photos": 201,
+            "total_collections": 0,
+            "instagram_username": "nasa",
+            "twitter_username": "nasa",
+            "profile_image": {
+                "small": "https://images.unsplash.com/profile-1446404465118?w=32",
+                "medium": "https://images.unsplash.com/profile-1446404465118?w=64",
+                "large": "https://images.unsplash.com/profile-1446404465118?w=128"
+            },
+            "links": {
+                "self": "https://api.unsplash.com/users/nasa",
+                "html": "https://unsplash.com/@nasa",
+                "photos": "https://api.unsplash.com/users/nasa/photos",
+                "likes": "https://api.unsplash.com/users/nasa/likes",
+                "portfolio": "https://api.unsplash.com/users/nasa/portfolio"
+            }
+        }]
+    }"#;
+
+    #[test]
+    fn photos() {
+        let results: SearchResults<Photo> = ::serde_json::from_str(PHOTOS).unwrap();
+        assert_eq!(results.total, 133);
+        assert_eq!(results.total_pages, 7);
+        assert_eq!(results.results[0].id, "eOLpJytrbsQ");
+        assert_eq!(results.results[0].user.username, "ugmonk");
+        assert!(results.next.is_none());
+    }
+
+    #[test]
+    fn collections() {
+        let results: SearchResults<Collection> = ::serde_json::from_str(COLLECTIONS).unwrap();
+        assert_eq!(results.total, 237);
+        assert_eq!(results.total_pages, 12);
+        assert_eq!(results.results[0].id, "193913");
+        assert_eq!(results.results[0].total_photos, Some(60));
+    }
+
+    #[test]
+    fn users() {
+        let results: SearchResults<User> = ::serde_json::from_str(USERS).unwrap();
+        assert_eq!(results.total, 44);
+        assert_eq!(results.total_pages, 3);
+        assert_eq!(results.results[0].username, "nasa");
+        assert_eq!(results.results[0].total_photos, 201);
+    }
+
+    #[test]
+    fn with_links() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            "<https://api.unsplash.com/search/photos?page=1&query=office>; rel=\"first\", \
+             <https://api.unsplash.com/search/photos?page=1&query=office>; rel=\"prev\", \
+             <https://api.unsplash.com/search/photos?page=7&query=office>; rel=\"last\", \
+             <https://api.unsplash.com/search/photos?page=3&query=office>; rel=\"next\""
+                .parse()
+                .unwrap(),
+        );
+        let results: SearchResults<Photo> = ::serde_json::from_str(PHOTOS).unwrap();
+        let results = results.with_links(&headers);
+        assert_eq!(results.total, 133);
+        assert_eq!(results.results.len(), 1);
+        assert_eq!(results.next.map(|cursor| cursor.page), Some(3));
+        assert_eq!(results.prev.map(|cursor| cursor.page), Some(1));
+        assert_eq!(results.first.map(|cursor| cursor.page), Some(1));
+        assert_eq!(results.last.map(|cursor| cursor.page), Some(7));
+    }
+}
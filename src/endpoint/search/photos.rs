@@ -0,0 +1,166 @@
+use client::Unsplash;
+use endpoint::photos::{Color, ContentFilter, Orientation, Photo};
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+use itertools::Itertools;
+
+use super::SearchResults;
+use error::*;
+
+/// Path of the endpoint to search for photos.
+pub const SEARCH_PHOTOS_PATH: &str = "search/photos";
+
+/// Request builder for creating a photo search request.
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchPhotos<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    query: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+    order_by: Option<SearchOrder>,
+    collections: Option<String>,
+    content_filter: Option<ContentFilter>,
+    color: Option<Color>,
+    orientation: Option<Orientation>,
+}
+
+/// Ordering of search results.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchOrder {
+    /// Most relevant comes first.
+    /// Default if unspecified.
+    Relevant,
+    /// Latest comes first.
+    Latest,
+}
+
+impl<'a, C> SearchPhotos<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, query: String) -> Self {
+        SearchPhotos {
+            client,
+            query,
+            page: None,
+            per_page: None,
+            order_by: None,
+            collections: None,
+            content_filter: None,
+            color: None,
+            orientation: None,
+        }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many photos per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Specify how to order the photos.
+    pub fn order_by(mut self, order_by: SearchOrder) -> Self {
+        self.order_by.replace(order_by);
+        self
+    }
+
+    /// Restrict the photos to only photos which are within the given
+    /// collections.
+    pub fn collections<I>(mut self, collections: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.collections.replace(collections.into_iter().join(","));
+        self
+    }
+
+    /// Restrict the photos to only photos which are safe for the given content
+    /// filter.
+    pub fn content_filter(mut self, content_filter: ContentFilter) -> Self {
+        self.content_filter.replace(content_filter);
+        self
+    }
+
+    /// Restrict the photos to only photos with the given color.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color.replace(color);
+        self
+    }
+
+    /// Restrict the photos to only photos with the given orientation.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation.replace(orientation);
+        self
+    }
+
+    /// Get the photos which match the query.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = SearchResults<Photo>, Error = Error> {
+        super::get(&self, self.client, SEARCH_PHOTOS_PATH)
+    }
+
+    /// Stream all the photos which match the query, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Photo, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, SearchResults<Photo>>(
+            &self,
+            self.client,
+            SEARCH_PHOTOS_PATH,
+            page,
+        )
+    }
+}
+
+impl Default for SearchOrder {
+    fn default() -> Self { SearchOrder::Relevant }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use endpoint::ToQuery;
+    use hyper::Client;
+
+    #[test]
+    fn query() {
+        let client = Unsplash::new(Client::new(), "access key".to_owned());
+        let search = SearchPhotos::new(&client, "office".to_owned())
+            .content_filter(ContentFilter::High)
+            .color(Color::BlackAndWhite);
+        assert_eq!(search.to_query(), "?query=office&content_filter=high&color=black_and_white");
+    }
+}
@@ -0,0 +1,79 @@
+use client::Unsplash;
+use endpoint::me::User;
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use super::SearchResults;
+use error::*;
+
+/// Path of the endpoint to search for users.
+pub const SEARCH_USERS_PATH: &str = "search/users";
+
+/// Request builder for creating a user search request.
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchUsers<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    query: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl<'a, C> SearchUsers<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, query: String) -> Self {
+        SearchUsers { client, query, page: None, per_page: None }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many users per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Get the users which match the query.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = SearchResults<User>, Error = Error> {
+        super::get(&self, self.client, SEARCH_USERS_PATH)
+    }
+
+    /// Stream all the users which match the query, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = User, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, SearchResults<User>>(&self, self.client, SEARCH_USERS_PATH, page)
+    }
+}
@@ -0,0 +1,164 @@
+use client::Unsplash;
+use futures::Future;
+use hyper::client::connect::Connect;
+
+use error::Error;
+
+/// Path of the endpoint to access the totals of Unsplash's statistics.
+pub const TOTAL_PATH: &str = "stats/total";
+/// Path of the endpoint to access Unsplash's statistics over the past 30 days.
+pub const MONTH_PATH: &str = "stats/month";
+
+/// Stats endpoint
+#[derive(Debug, Clone)]
+pub struct Stats<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
+
+/// Totals of Unsplash's statistics.
+///
+/// Only the photo counters are required, so a missing or renamed counter
+/// doesn't fail the whole response.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct TotalStats {
+    /// Number of photos on Unsplash.
+    pub photos: u64,
+    /// Number of photo downloads.
+    pub downloads: u64,
+    /// Number of photo views.
+    pub views: u64,
+    /// Number of photographers on Unsplash.
+    pub photographers: Option<u64>,
+    /// Number of pixels in all of the photos.
+    pub pixels: Option<u64>,
+    /// Average number of downloads per second.
+    pub downloads_per_second: Option<u64>,
+    /// Average number of views per second.
+    pub views_per_second: Option<u64>,
+    /// Number of developers using the API.
+    pub developers: Option<u64>,
+    /// Number of applications using the API.
+    pub applications: Option<u64>,
+    /// Number of requests made to the API.
+    pub requests: Option<u64>,
+}
+
+/// Unsplash's statistics over the past 30 days.
+///
+/// As with [TotalStats](struct.TotalStats.html), only the photo counters are
+/// required.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct MonthStats {
+    /// Number of photo downloads.
+    pub downloads: u64,
+    /// Number of photo views.
+    pub views: u64,
+    /// Number of new photos.
+    pub new_photos: u64,
+    /// Number of new photographers.
+    pub new_photographers: Option<u64>,
+    /// Number of pixels in the new photos.
+    pub new_pixels: Option<u64>,
+    /// Number of new developers using the API.
+    pub new_developers: Option<u64>,
+    /// Number of new applications using the API.
+    pub new_applications: Option<u64>,
+    /// Number of new requests made to the API.
+    pub new_requests: Option<u64>,
+}
+
+impl<'a, C> Stats<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Stats { client } }
+
+    /// Gets the totals of Unsplash's statistics.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn total(&self) -> impl Future<Item = TotalStats, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(TOTAL_PATH))
+    }
+
+    /// Gets Unsplash's statistics over the past 30 days.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn month(&self) -> impl Future<Item = MonthStats, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(MONTH_PATH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Totals as documented for /stats/total.
+    const TOTAL: &str = r#"{
+        "photos": 1000000,
+        "downloads": 4000000,
+        "views": 99999999,
+        "photographers": 40000,
+        "pixels": 10000000,
+        "downloads_per_second": 30,
+        "views_per_second": 1000,
+        "developers": 20000,
+        "applications": 1000,
+        "requests": 1000000
+    }"#;
+
+    /// Statistics as documented for /stats/month.
+    const MONTH: &str = r#"{
+        "downloads": 40000,
+        "views": 10000000,
+        "new_photos": 1000,
+        "new_photographers": 500,
+        "new_pixels": 10000000,
+        "new_developers": 500,
+        "new_applications": 50,
+        "new_requests": 1000000
+    }"#;
+
+    #[test]
+    fn total() {
+        let total: TotalStats = ::serde_json::from_str(TOTAL).unwrap();
+        assert_eq!(total.photos, 1000000);
+        assert_eq!(total.views, 99999999);
+        assert_eq!(total.photographers, Some(40000));
+        assert_eq!(total.downloads_per_second, Some(30));
+        assert_eq!(total.requests, Some(1000000));
+    }
+
+    #[test]
+    fn month() {
+        let month: MonthStats = ::serde_json::from_str(MONTH).unwrap();
+        assert_eq!(month.downloads, 40000);
+        assert_eq!(month.new_photos, 1000);
+        assert_eq!(month.new_pixels, Some(10000000));
+        assert_eq!(month.new_applications, Some(50));
+    }
+
+    #[test]
+    fn missing_counters() {
+        let total: TotalStats =
+            ::serde_json::from_str(r#"{"photos": 1, "downloads": 2, "views": 3}"#).unwrap();
+        assert_eq!(total.photographers, None);
+        assert_eq!(total.requests, None);
+
+        let month: MonthStats =
+            ::serde_json::from_str(r#"{"downloads": 1, "views": 2, "new_photos": 3}"#).unwrap();
+        assert_eq!(month.new_photographers, None);
+        assert_eq!(month.new_requests, None);
+    }
+}
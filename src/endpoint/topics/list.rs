@@ -0,0 +1,116 @@
+use client::Unsplash;
+use endpoint::Page;
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+use itertools::Itertools;
+
+use super::Topic;
+use error::*;
+
+/// Path of the endpoint to get a list of topics from Unsplash.
+pub const LIST_PATH: &str = "topics";
+
+/// Request builder for creating a topic List request.
+#[derive(Debug, Serialize, Clone)]
+pub struct List<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    ids: Option<String>,
+    page: Option<usize>,
+    per_page: Option<usize>,
+    order_by: Option<TopicOrder>,
+}
+
+/// Ordering of topics.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TopicOrder {
+    /// Featured topics come first.
+    Featured,
+    /// Latest comes first.
+    Latest,
+    /// Oldest comes first.
+    Oldest,
+    /// Ordered by the position Unsplash gives each topic.
+    /// Default if unspecified.
+    Position,
+}
+
+impl<'a, C> List<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self {
+        List { client, ids: None, page: None, per_page: None, order_by: None }
+    }
+
+    /// Restrict the topics to only the topics with the given IDs or slugs.
+    pub fn ids<I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.ids.replace(ids.into_iter().join(","));
+        self
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many topics per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Specify how to order the topics.
+    pub fn order_by(mut self, order_by: TopicOrder) -> Self {
+        self.order_by.replace(order_by);
+        self
+    }
+
+    /// Get the list of topics.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = Page<Topic>, Error = Error> {
+        let client = self.client;
+        ::endpoint::get_page(&self, client, &client.auth(), client.url(LIST_PATH))
+    }
+
+    /// Stream all the topics, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Topic, Error = Error> {
+        let page = self.page.take();
+        ::endpoint::paginate::<_, Vec<Topic>>(&self, self.client, LIST_PATH, page)
+    }
+}
+
+impl Default for TopicOrder {
+    fn default() -> Self { TopicOrder::Position }
+}
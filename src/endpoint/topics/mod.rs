@@ -0,0 +1,201 @@
+//! Topics endpoint
+//!
+//! Access to the endpoint is through the [Topics](struct.Topics.html) struct,
+//! which is created by [Unsplash::topics](../../client/struct.Unsplash.html#method.topics).
+
+use chrono::{DateTime, FixedOffset};
+use client::Unsplash;
+use endpoint::{me::User,
+               photos::{Photo, Urls}};
+use futures::Future;
+use hyper::client::connect::Connect;
+
+mod list;
+mod photos;
+
+use error::*;
+
+pub use self::{list::{List, TopicOrder},
+               photos::TopicPhotos};
+
+/// Access type to Unsplash's Topics endpoint.
+#[derive(Debug, Clone)]
+pub struct Topics<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
+
+/// A topic on Unsplash, which groups photos about a subject.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Topic {
+    /// Topic ID.
+    pub id: String,
+    /// Slug of the topic, which can be used in place of its ID.
+    pub slug: String,
+    /// Topic's title.
+    pub title: String,
+    /// Topic's description.
+    pub description: Option<String>,
+    /// Date when the topic was published.
+    pub published_at: DateTime<FixedOffset>,
+    /// Last date when the topic was updated.
+    pub updated_at: DateTime<FixedOffset>,
+    /// Is the topic featured.
+    pub featured: bool,
+    /// Is the topic open for submissions.
+    pub status: TopicStatus,
+    /// Number of photos in the topic.
+    pub total_photos: usize,
+    /// Users who curate the topic.
+    pub owners: Vec<User>,
+    /// Photo used as the topic's cover.
+    pub cover_photo: Option<Photo>,
+    /// A preview of the photos in the topic.
+    pub preview_photos: Option<Vec<PreviewPhoto>>,
+    /// Links to the topic.
+    pub links: TopicLinks,
+}
+
+/// Whether a topic is open for submissions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TopicStatus {
+    /// Open for submissions.
+    Open,
+    /// Closed for submissions.
+    Closed,
+}
+
+/// A photo previewing a topic.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewPhoto {
+    /// Photo ID.
+    pub id: String,
+    /// Photo creation date.
+    pub created_at: DateTime<FixedOffset>,
+    /// Last time photo was updated.
+    pub updated_at: DateTime<FixedOffset>,
+    /// Urls to the photo in various sizes.
+    pub urls: Urls,
+}
+
+/// Links to a topic.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopicLinks {
+    /// API link to the topic.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// Link to the topic.
+    pub html: String,
+    /// API link to the topic's photos.
+    pub photos: String,
+}
+
+impl<'a, C> Topics<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Topics { client } }
+
+    /// Get a list of topics from Unsplash
+    pub fn list(&self) -> List<'a, C> { List::new(self.client) }
+
+    /// Get the topic with the given ID or slug from Unsplash.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(&self, id_or_slug: &str) -> impl Future<Item = Topic, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(&format!("topics/{}", id_or_slug)))
+    }
+
+    /// Get the photos in the topic with the given ID or slug from Unsplash.
+    pub fn photos(&self, id_or_slug: &str) -> TopicPhotos<'a, C> {
+        TopicPhotos::new(self.client, id_or_slug.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A topic as documented for /topics/:id_or_slug.
+    const TOPIC: &str = r#"{
+        "id": "bo8jQKTaE0Y",
+        "slug": "wallpapers",
+        "title": "Wallpapers",
+        "description": "From epic drone shots to inspiring moments in nature.",
+        "published_at": "2020-04-15T17:31:14Z",
+        "updated_at": "2021-01-21T15:36:10Z",
+        "starts_at": "2020-04-15T00:00:00Z",
+        "ends_at": null,
+        "only_submissions_after": null,
+        "featured": true,
+        "total_photos": 6247,
+        "links": {
+            "self": "https://api.unsplash.com/topics/wallpapers",
+            "html": "https://unsplash.com/t/wallpapers",
+            "photos": "https://api.unsplash.com/topics/wallpapers/photos"
+        },
+        "status": "open",
+        "owners": [{
+            "id": "QV5S1rtoUJ0",
+            "updated_at": "2021-01-21T15:35:59-05:00",
+            "username": "unsplash",
+            "name": "Unsplash",
+            "first_name": "Unsplash",
+            "last_name": null,
+            "twitter_username": "unsplash",
+            "portfolio_url": "https://unsplash.com",
+            "bio": "Behind the scenes of the team building the internet's open library.",
+            "location": "Montreal, Canada",
+            "links": {
+                "self": "https://api.unsplash.com/users/unsplash",
+                "html": "https://unsplash.com/@unsplash",
+                "photos": "https://api.unsplash.com/users/unsplash/photos",
+                "likes": "https://api.unsplash.com/users/unsplash/likes",
+                "portfolio": "https://api.unsplash.com/users/unsplash/portfolio"
+            },
+            "profile_image": {
+                "small": "https://images.unsplash.com/profile-1544707963613?w=32",
+                "medium": "https://images.unsplash.com/profile-1544707963613?w=64",
+                "large": "https://images.unsplash.com/profile-1544707963613?w=128"
+            },
+            "instagram_username": "unsplash",
+            "total_collections": 6,
+            "total_likes": 16237,
+            "total_photos": 0,
+            "accepted_tos": true
+        }],
+        "current_user_contributions": [],
+        "total_current_user_submissions": null,
+        "cover_photo": null,
+        "preview_photos": [{
+            "id": "D4YrzSwyIEc",
+            "created_at": "2021-01-21T10:09:27-05:00",
+            "updated_at": "2021-01-21T15:35:37-05:00",
+            "urls": {
+                "raw": "https://images.unsplash.com/photo-1611241893603",
+                "full": "https://images.unsplash.com/photo-1611241893603?q=85",
+                "regular": "https://images.unsplash.com/photo-1611241893603?w=1080",
+                "small": "https://images.unsplash.com/photo-1611241893603?w=400",
+                "thumb": "https://images.unsplash.com/photo-1611241893603?w=200"
+            }
+        }]
+    }"#;
+
+    #[test]
+    fn topic() {
+        let topic: Topic = ::serde_json::from_str(TOPIC).unwrap();
+        assert_eq!(topic.slug, "wallpapers");
+        assert_eq!(topic.status, TopicStatus::Open);
+        assert!(topic.featured);
+        assert_eq!(topic.total_photos, 6247);
+        assert_eq!(topic.owners[0].username, "unsplash");
+        assert!(topic.cover_photo.is_none());
+        assert_eq!(topic.preview_photos.unwrap()[0].id, "D4YrzSwyIEc");
+    }
+}
@@ -0,0 +1,101 @@
+use client::Unsplash;
+use endpoint::{photos::{Order, Orientation, Photo},
+               Page};
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use error::*;
+
+/// Request builder for creating a request for the photos in a topic.
+#[derive(Debug, Serialize, Clone)]
+pub struct TopicPhotos<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    id_or_slug: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+    orientation: Option<Orientation>,
+    order_by: Option<Order>,
+}
+
+impl<'a, C> TopicPhotos<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, id_or_slug: String) -> Self {
+        TopicPhotos {
+            client,
+            id_or_slug,
+            page: None,
+            per_page: None,
+            orientation: None,
+            order_by: None,
+        }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many photos per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Restrict the photos to only photos with the given orientation.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation.replace(orientation);
+        self
+    }
+
+    /// Specify how to order the photos.
+    pub fn order_by(mut self, order_by: Order) -> Self {
+        self.order_by.replace(order_by);
+        self
+    }
+
+    /// Get the photos in the topic.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = Page<Photo>, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("topics/{}/photos", self.id_or_slug));
+        ::endpoint::get_page(&self, client, &client.auth(), uri)
+    }
+
+    /// Stream all the photos in the topic, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Photo, Error = Error> {
+        let page = self.page.take();
+        let path = format!("topics/{}/photos", self.id_or_slug);
+        ::endpoint::paginate::<_, Vec<Photo>>(&self, self.client, &path, page)
+    }
+}
@@ -0,0 +1,79 @@
+use client::Unsplash;
+use endpoint::{collections::Collection, Page};
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use error::*;
+
+/// Request builder for creating a request for a user's collections.
+#[derive(Debug, Serialize, Clone)]
+pub struct UserCollections<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    username: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl<'a, C> UserCollections<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, username: String) -> Self {
+        UserCollections { client, username, page: None, per_page: None }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many collections per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Get the collections created by the user.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = Page<Collection>, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("users/{}/collections", self.username));
+        ::endpoint::get_page(&self, client, &client.auth(), uri)
+    }
+
+    /// Stream all the collections created by the user, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Collection, Error = Error> {
+        let page = self.page.take();
+        let path = format!("users/{}/collections", self.username);
+        ::endpoint::paginate::<_, Vec<Collection>>(&self, self.client, &path, page)
+    }
+}
@@ -0,0 +1,145 @@
+//! Users endpoint
+//!
+//! Access to the endpoint is through the [Users](struct.Users.html) struct,
+//! which is created by [Unsplash::users](../../client/struct.Unsplash.html#method.users).
+
+use auth::Bearer;
+use client::Unsplash;
+use endpoint::{me::User, photos::Url};
+use futures::{future::Either, Future};
+use hyper::client::connect::Connect;
+use oauth::Scope;
+
+mod collections;
+mod followers;
+mod following;
+mod likes;
+mod photos;
+mod statistics;
+
+use error::*;
+
+pub use self::{collections::UserCollections,
+               followers::UserFollowers,
+               following::UserFollowing,
+               likes::UserLikes,
+               photos::UserPhotos,
+               statistics::{Statistics, UserStatistics}};
+
+/// Access type to Unsplash's Users endpoint.
+#[derive(Debug, Clone)]
+pub struct Users<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+}
+
+impl<'a, C> Users<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>) -> Self { Users { client } }
+
+    /// Get the public profile of the user with the given username.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(&self, username: &str) -> impl Future<Item = User, Error = Error> {
+        let client = self.client;
+        ::endpoint::get(&(), client, &client.auth(), client.url(&format!("users/{}", username)))
+    }
+
+    /// Get the photos uploaded by the user with the given username.
+    pub fn photos(&self, username: &str) -> UserPhotos<'a, C> {
+        UserPhotos::new(self.client, username.to_owned())
+    }
+
+    /// Get the photos liked by the user with the given username.
+    pub fn likes(&self, username: &str) -> UserLikes<'a, C> {
+        UserLikes::new(self.client, username.to_owned())
+    }
+
+    /// Get the collections created by the user with the given username.
+    pub fn collections(&self, username: &str) -> UserCollections<'a, C> {
+        UserCollections::new(self.client, username.to_owned())
+    }
+
+    /// Get the users following the user with the given username.
+    pub fn followers(&self, username: &str) -> UserFollowers<'a, C> {
+        UserFollowers::new(self.client, username.to_owned())
+    }
+
+    /// Get the users the user with the given username follows.
+    pub fn following(&self, username: &str) -> UserFollowing<'a, C> {
+        UserFollowing::new(self.client, username.to_owned())
+    }
+
+    /// Follow the user with the given username on behalf of the user the
+    /// bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_followers.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn follow(&self, username: &str, bearer: &Bearer) -> impl Future<Item = (), Error = Error> {
+        let client = self.client;
+        match bearer.auth(Scope::WriteFollowers) {
+            Ok(auth) => {
+                let uri = client.url(&format!("users/{}/follow", username));
+                Either::A(::endpoint::post(&(), client, &auth, uri).map(::endpoint::ignore))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+
+    /// Unfollow the user with the given username on behalf of the user the
+    /// bearer token belongs to.
+    ///
+    /// # Errors
+    /// - MissingScope is raised if the bearer token doesn't grant
+    /// write_followers.
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn unfollow(
+        &self,
+        username: &str,
+        bearer: &Bearer,
+    ) -> impl Future<Item = (), Error = Error> {
+        let client = self.client;
+        match bearer.auth(Scope::WriteFollowers) {
+            Ok(auth) => {
+                let uri = client.url(&format!("users/{}/follow", username));
+                Either::A(::endpoint::delete(&(), client, &auth, uri).map(::endpoint::ignore))
+            },
+            Err(e) => Either::B(::futures::future::err(e)),
+        }
+    }
+
+    /// Get the statistics of the user with the given username.
+    pub fn statistics(&self, username: &str) -> Statistics<'a, C> {
+        Statistics::new(self.client, username.to_owned())
+    }
+
+    /// Get the URL of the portfolio of the user with the given username.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn portfolio(&self, username: &str) -> impl Future<Item = Url, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("users/{}/portfolio", username));
+        ::endpoint::get(&(), client, &client.auth(), uri)
+    }
+}
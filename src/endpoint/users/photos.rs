@@ -0,0 +1,131 @@
+use client::Unsplash;
+use endpoint::{photos::{Order, Orientation, Photo, Resolution},
+               Page};
+use futures::{Future, Stream};
+use hyper::client::connect::Connect;
+
+use error::*;
+
+/// Request builder for creating a request for a user's photos.
+#[derive(Debug, Serialize, Clone)]
+pub struct UserPhotos<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    username: String,
+    page: Option<usize>,
+    per_page: Option<usize>,
+    order_by: Option<Order>,
+    stats: Option<bool>,
+    resolution: Option<Resolution>,
+    quantity: Option<usize>,
+    orientation: Option<Orientation>,
+}
+
+impl<'a, C> UserPhotos<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, username: String) -> Self {
+        UserPhotos {
+            client,
+            username,
+            page: None,
+            per_page: None,
+            order_by: None,
+            stats: None,
+            resolution: None,
+            quantity: None,
+            orientation: None,
+        }
+    }
+
+    /// Specify which page to access.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if page is 0.
+    pub fn page(mut self, page: usize) -> Self {
+        assert_ne!(0, page, "Pages start a 1, not 0!");
+        self.page.replace(page);
+        self
+    }
+
+    /// Specify how many photos per page.
+    ///
+    /// Unsplash uses pagination.
+    ///
+    /// # Panics
+    /// Panics if per_page is 0.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        assert_ne!(0, per_page, "Cannot have 0 elements per page!");
+        self.per_page.replace(per_page);
+        self
+    }
+
+    /// Specify how to order the photos.
+    pub fn order_by(mut self, order_by: Order) -> Self {
+        self.order_by.replace(order_by);
+        self
+    }
+
+    /// Specify whether to include the statistics of each photo, in the
+    /// photo's [statistics](../photos/struct.Photo.html#structfield.statistics).
+    pub fn stats(mut self, stats: bool) -> Self {
+        self.stats.replace(stats);
+        self
+    }
+
+    /// Specify the resolution of the history of each photo's statistics.
+    pub fn resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution.replace(resolution);
+        self
+    }
+
+    /// Specify how many data points of history to get for each photo's
+    /// statistics.
+    ///
+    /// # Panics
+    /// Panics if quantity is not between 1 and 30.
+    pub fn quantity(mut self, quantity: usize) -> Self {
+        assert!(quantity >= 1 && quantity <= 30, "Quantity must be between 1 and 30!");
+        self.quantity.replace(quantity);
+        self
+    }
+
+    /// Restrict the photos to only photos with the given orientation.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation.replace(orientation);
+        self
+    }
+
+    /// Get the photos uploaded by the user.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = Page<Photo>, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("users/{}/photos", self.username));
+        ::endpoint::get_page(&self, client, &client.auth(), uri)
+    }
+
+    /// Stream all the photos uploaded by the user, requesting each page as
+    /// [Page](../struct.Page.html) describes.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn stream(mut self) -> impl Stream<Item = Photo, Error = Error> {
+        let page = self.page.take();
+        let path = format!("users/{}/photos", self.username);
+        ::endpoint::paginate::<_, Vec<Photo>>(&self, self.client, &path, page)
+    }
+}
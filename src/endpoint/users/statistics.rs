@@ -0,0 +1,69 @@
+use client::Unsplash;
+use endpoint::photos::{Resolution, Statistic};
+use futures::Future;
+use hyper::client::connect::Connect;
+
+use error::*;
+
+/// Request builder for creating a user Statistics request.
+#[derive(Debug, Serialize, Clone)]
+pub struct Statistics<'a, C: 'a> {
+    #[serde(skip)]
+    client: &'a Unsplash<C>,
+    #[serde(skip)]
+    username: String,
+    resolution: Option<Resolution>,
+    quantity: Option<usize>,
+}
+
+/// Statistics of a user.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserStatistics {
+    /// User ID.
+    pub id: String,
+    /// Username.
+    pub username: String,
+    /// Downloads of the user's photos.
+    pub downloads: Statistic,
+    /// Views of the user's photos.
+    pub views: Statistic,
+}
+
+impl<'a, C> Statistics<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, username: String) -> Self {
+        Statistics { client, username, resolution: None, quantity: None }
+    }
+
+    /// Specify the resolution of the history.
+    pub fn resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution.replace(resolution);
+        self
+    }
+
+    /// Specify how many data points of history to get.
+    ///
+    /// # Panics
+    /// Panics if quantity is not between 1 and 30.
+    pub fn quantity(mut self, quantity: usize) -> Self {
+        assert!(quantity >= 1 && quantity <= 30, "Quantity must be between 1 and 30!");
+        self.quantity.replace(quantity);
+        self
+    }
+
+    /// Get the statistics of the user.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn get(self) -> impl Future<Item = UserStatistics, Error = Error> {
+        let client = self.client;
+        let uri = client.url(&format!("users/{}/statistics", self.username));
+        ::endpoint::get(&self, client, &client.auth(), uri)
+    }
+}
@@ -1,4 +1,5 @@
 use failure::{Backtrace, Context, Fail};
+use oauth::Scope;
 
 use std::fmt;
 
@@ -20,6 +21,19 @@ pub enum ErrorKind {
     #[fail(display = "Not authorized to access endpoint.")]
     Forbidden,
 
+    /// Raised when the caller's bearer doesn't grant the scope needed to
+    /// access an endpoint.
+    #[fail(display = "Bearer token is missing the {} scope.", _0)]
+    MissingScope(Scope),
+
+    /// Raised when Unsplash responds with an unexpected status code.
+    #[fail(display = "Unexpected status code {} from Unsplash.", _0)]
+    UnexpectedStatus(u16),
+
+    /// Raised when a photo cannot be saved to disk.
+    #[fail(display = "Failed to save photo.")]
+    Io,
+
     /// Raised when the response from Unsplash cannot be understood.
     #[fail(display = "Failed to parse response from Unsplash.")]
     MalformedResponse,
@@ -17,8 +17,6 @@ extern crate serde_derive;
 #[macro_use]
 extern crate failure;
 #[macro_use]
-extern crate lazy_static;
-#[macro_use]
 extern crate log;
 extern crate chrono;
 extern crate futures;
@@ -27,14 +25,28 @@ extern crate itertools;
 extern crate serde;
 extern crate serde_json;
 extern crate serde_url_params;
+#[cfg(test)]
+extern crate tokio;
 
 /// Root URI of the Unsplash API.
 pub const API_URL: &'static str = "https://api.unsplash.com/";
 
+/// Credentials used to access the Unsplash API.
+pub mod auth;
+
+/// Client used to access the Unsplash API.
+pub mod client;
+
 /// Endpoints of the Unsplash API.
 pub mod endpoint;
 
 /// Errors that can be raised.
 pub mod error;
 
-pub use endpoint::{me::Me, photos::Photos};
+/// Authorization of access on behalf of a user.
+pub mod oauth;
+
+pub use auth::{Auth, Bearer};
+pub use client::Unsplash;
+pub use endpoint::{collections::Collections, me::Me, photos::Photos, search::Search, stats::Stats,
+                   topics::Topics, users::Users};
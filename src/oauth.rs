@@ -0,0 +1,320 @@
+//! OAuth2 authorization code flow
+//!
+//! Access to the flow is through the [OAuth](struct.OAuth.html) struct, which
+//! is created by [Unsplash::oauth](../client/struct.Unsplash.html#method.oauth).
+
+use auth::Bearer;
+use chrono::{DateTime, Utc};
+use client::Unsplash;
+use futures::Future;
+use hyper::client::connect::Connect;
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use std::{fmt, str::FromStr};
+
+use endpoint::ToQuery;
+use error::*;
+
+/// Root URI of Unsplash's OAuth endpoints.
+pub const OAUTH_URL: &str = "https://unsplash.com/oauth/";
+
+/// Permissions which can be requested from a user.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    /// Read public data.
+    Public,
+    /// Access the user's private data.
+    ReadUser,
+    /// Update the user's profile.
+    WriteUser,
+    /// Read private data from the user's photos.
+    ReadPhotos,
+    /// Update photos on the user's behalf.
+    WritePhotos,
+    /// Like or unlike photos on the user's behalf.
+    WriteLikes,
+    /// Follow or unfollow users on the user's behalf.
+    WriteFollowers,
+    /// View the user's private collections.
+    ReadCollections,
+    /// Create and update the user's collections.
+    WriteCollections,
+}
+
+/// Builder for authorizing access to Unsplash on behalf of a user.
+#[derive(Clone)]
+pub struct OAuth<'a, C: 'a> {
+    client: &'a Unsplash<C>,
+    secret_key: String,
+    redirect_uri: String,
+    scopes: Vec<Scope>,
+    oauth_url: String,
+}
+
+/// An access token returned from Unsplash after authorization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    /// Access token used as the bearer token.
+    pub access_token: String,
+    /// Type of the token, always bearer.
+    pub token_type: String,
+    /// Refresh token, if one was issued.
+    pub refresh_token: Option<String>,
+    /// Scopes the token grants access to.
+    #[serde(deserialize_with = "deserialize_scopes", serialize_with = "serialize_scopes")]
+    pub scope: Vec<Scope>,
+    /// When the token was created.
+    #[serde(with = "::chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Serialization type for the authorize URL's query.
+#[derive(Debug, Serialize)]
+struct AuthorizeSerialize<'a> {
+    client_id: &'a str,
+    redirect_uri: &'a str,
+    response_type: &'static str,
+    scope: String,
+}
+
+/// Serialization type for the token request's form.
+#[derive(Debug, Serialize)]
+struct TokenSerialize<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    redirect_uri: &'a str,
+    code: String,
+    grant_type: &'static str,
+}
+
+impl<'a, C> OAuth<'a, C>
+where
+    C: Connect + 'static,
+{
+    pub(crate) fn new(client: &'a Unsplash<C>, secret_key: String, redirect_uri: String) -> Self {
+        OAuth {
+            client,
+            secret_key,
+            redirect_uri,
+            scopes: Vec::new(),
+            oauth_url: OAUTH_URL.to_owned(),
+        }
+    }
+
+    /// Request the given scope from the user.
+    ///
+    /// If no scopes are requested, Unsplash only grants the public scope.
+    pub fn scope(mut self, scope: Scope) -> Self {
+        if !self.scopes.contains(&scope) {
+            self.scopes.push(scope);
+        }
+        self
+    }
+
+    /// Request all of the given scopes from the user.
+    pub fn scopes<I>(self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = Scope>,
+    {
+        scopes.into_iter().fold(self, OAuth::scope)
+    }
+
+    /// Specify the root URI of the OAuth endpoints, instead of
+    /// [OAUTH_URL](constant.OAUTH_URL.html).
+    ///
+    /// The URI must end with a '/'.
+    pub fn oauth_url(mut self, oauth_url: String) -> Self {
+        self.oauth_url = oauth_url;
+        self
+    }
+
+    /// The URI to send the user to, so they can authorize access.
+    ///
+    /// Once authorized, Unsplash redirects the user to the redirect URI with
+    /// the authorization code in the code query parameter.
+    pub fn authorize_url(&self) -> String {
+        let serial = AuthorizeSerialize {
+            client_id: self.client.access_key(),
+            redirect_uri: &self.redirect_uri,
+            response_type: "code",
+            scope: self.scopes.iter().join(" "),
+        };
+        format!("{}authorize{}", self.oauth_url, serial.to_query())
+    }
+
+    /// Exchange the authorization code returned from Unsplash for an access
+    /// token.
+    ///
+    /// # Errors
+    /// - Request wrapping a Hyper error is raised if there is an error
+    /// handling the HTTP Stream.
+    /// - MalformedResponse
+    ///     - wrapping a JSON error is raised if the JSON returned from
+    /// Unsplash is invalid.
+    pub fn token(self, code: String) -> impl Future<Item = Token, Error = Error> {
+        let serial = TokenSerialize {
+            client_id: self.client.access_key(),
+            client_secret: &self.secret_key,
+            redirect_uri: &self.redirect_uri,
+            code,
+            grant_type: "authorization_code",
+        };
+        ::endpoint::post_form(&serial, self.client, format!("{}token", self.oauth_url))
+    }
+}
+
+// Debug is written by hand so the secret key is never printed.
+impl<'a, C> fmt::Debug for OAuth<'a, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("OAuth")
+            .field("client", &self.client)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("scopes", &self.scopes)
+            .field("oauth_url", &self.oauth_url)
+            .finish()
+    }
+}
+
+impl Token {
+    /// The bearer token used to access Unsplash on behalf of the user, which
+    /// knows the scopes it grants.
+    pub fn bearer(&self) -> Bearer {
+        Bearer::with_scopes(self.access_token.clone(), self.scope.clone())
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match *self {
+            Scope::Public => "public",
+            Scope::ReadUser => "read_user",
+            Scope::WriteUser => "write_user",
+            Scope::ReadPhotos => "read_photos",
+            Scope::WritePhotos => "write_photos",
+            Scope::WriteLikes => "write_likes",
+            Scope::WriteFollowers => "write_followers",
+            Scope::ReadCollections => "read_collections",
+            Scope::WriteCollections => "write_collections",
+        })
+    }
+}
+
+impl FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "public" => Ok(Scope::Public),
+            "read_user" => Ok(Scope::ReadUser),
+            "write_user" => Ok(Scope::WriteUser),
+            "read_photos" => Ok(Scope::ReadPhotos),
+            "write_photos" => Ok(Scope::WritePhotos),
+            "write_likes" => Ok(Scope::WriteLikes),
+            "write_followers" => Ok(Scope::WriteFollowers),
+            "read_collections" => Ok(Scope::ReadCollections),
+            "write_collections" => Ok(Scope::WriteCollections),
+            _ => Err(Error::from(ErrorKind::MalformedResponse)),
+        }
+    }
+}
+
+/// Unsplash returns the scopes of a token as a space separated String.
+///
+/// Unknown scopes are skipped rather than failing, as the authorization code
+/// the token was exchanged for cannot be used again.
+fn deserialize_scopes<'de, D>(deserializer: D) -> Result<Vec<Scope>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(String::deserialize(deserializer)?
+        .split_whitespace()
+        .filter_map(|s| match s.parse() {
+            Ok(scope) => Some(scope),
+            Err(_) => {
+                warn!("skipping unknown scope {}", s);
+                None
+            },
+        })
+        .collect())
+}
+
+/// Serialize scopes in the same form Unsplash returns them.
+fn serialize_scopes<S>(scopes: &[Scope], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&scopes.iter().join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{sync::oneshot, Stream};
+    use hyper::{header::{AUTHORIZATION, CONTENT_TYPE},
+                service::service_fn,
+                Body, Client, Method, Request, Response, Server};
+    use tokio::runtime::Runtime;
+
+    use std::sync::{Arc, Mutex};
+
+    const TOKEN: &str = r#"{
+        "access_token": "091343ce13c8ae780065ecb3b13dc903475dd22cb78a05503c2e0c69c5e98044",
+        "token_type": "bearer",
+        "scope": "public read_photos write_photos read_future_scope",
+        "created_at": 1436544465
+    }"#;
+
+    #[test]
+    fn token() {
+        let mut rt = Runtime::new().unwrap();
+        let (tx, rx) = oneshot::channel();
+        let tx = Arc::new(Mutex::new(Some(tx)));
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(move || {
+            let tx = tx.clone();
+            service_fn(move |req: Request<Body>| {
+                let tx = tx.clone();
+                let (parts, body) = req.into_parts();
+                body.concat2().map(move |body| {
+                    if let Some(tx) = tx.lock().unwrap().take() {
+                        let _ = tx.send((parts, body.to_vec()));
+                    }
+                    Response::new(Body::from(TOKEN))
+                })
+            })
+        });
+        let addr = server.local_addr();
+        rt.spawn(server.map_err(|e| panic!("{}", e)));
+
+        let client = Unsplash::new(Client::new(), "access key".to_owned());
+        let token = rt
+            .block_on(
+                client
+                    .oauth("secret key".to_owned(), "https://example.com/auth".to_owned())
+                    .oauth_url(format!("http://{}/", addr))
+                    .token("auth code".to_owned()),
+            )
+            .unwrap();
+
+        assert_eq!(
+            token.access_token,
+            "091343ce13c8ae780065ecb3b13dc903475dd22cb78a05503c2e0c69c5e98044"
+        );
+        assert_eq!(token.token_type, "bearer");
+        assert_eq!(token.refresh_token, None);
+        assert_eq!(token.scope, vec![Scope::Public, Scope::ReadPhotos, Scope::WritePhotos]);
+        assert_eq!(token.created_at.timestamp(), 1436544465);
+
+        let (parts, body) = rt.block_on(rx).unwrap();
+        assert_eq!(parts.method, Method::POST);
+        assert_eq!(parts.uri, "/token");
+        assert!(parts.headers.get(AUTHORIZATION).is_none());
+        assert_eq!(parts.headers[CONTENT_TYPE], "application/x-www-form-urlencoded");
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "client_id=access+key&client_secret=secret+key&redirect_uri=https%3A%2F%2Fexample.com%\
+             2Fauth&code=auth+code&grant_type=authorization_code"
+        );
+    }
+}